@@ -0,0 +1,28 @@
+use day_03::{render, Schematic};
+
+// Usage: render [input file] [--html <output file>]
+fn main() -> std::io::Result<()> {
+    let mut input_path = None;
+    let mut html_path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--html" => html_path = args.next(),
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let input = match input_path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+    let schematic = Schematic::parse(&input);
+
+    match html_path {
+        Some(path) => std::fs::write(path, render::to_html(&input, &schematic)),
+        None => {
+            print!("{}", render::to_ansi(&input, &schematic));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub mod render;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Number {
+    pub id: usize,
+    pub value: u32,
+    pub row: usize,
+    // Columns covered by the digits, `end` is exclusive
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Number {
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        self.row == row && (self.start..self.end).contains(&col)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Symbol {
+    pub id: usize,
+    pub char: char,
+    pub row: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    // symbol id -> ids of the numbers touching it
+    parts_by_symbol: BTreeMap<usize, BTreeSet<usize>>,
+    // number id -> ids of the symbols touching it
+    symbols_by_part: BTreeMap<usize, BTreeSet<usize>>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Self {
+        let mut schematic = Schematic::default();
+
+        for (row, line) in input.lines().enumerate() {
+            let mut chars = line.char_indices().peekable();
+            while let Some((col, c)) = chars.next() {
+                if let Some(digit) = c.to_digit(10) {
+                    let mut value = digit;
+                    let mut end = col + 1;
+                    while let Some(digit) = chars.peek().and_then(|(_, c)| c.to_digit(10)) {
+                        value = value * 10 + digit;
+                        end += 1;
+                        chars.next();
+                    }
+                    schematic.numbers.push(Number {
+                        id: schematic.numbers.len(),
+                        value,
+                        row,
+                        start: col,
+                        end,
+                    });
+                } else if c != '.' && !c.is_whitespace() {
+                    schematic.symbols.push(Symbol {
+                        id: schematic.symbols.len(),
+                        char: c,
+                        row,
+                        col,
+                    });
+                }
+            }
+        }
+
+        schematic.build_index();
+        schematic
+    }
+
+    fn build_index(&mut self) {
+        let symbol_positions: HashMap<(usize, usize), usize> = self
+            .symbols
+            .iter()
+            .map(|symbol| ((symbol.row, symbol.col), symbol.id))
+            .collect();
+
+        for number in &self.numbers {
+            for row in number.row.saturating_sub(1)..=number.row + 1 {
+                for col in number.start.saturating_sub(1)..=number.end {
+                    let Some(&symbol_id) = symbol_positions.get(&(row, col)) else {
+                        continue;
+                    };
+                    self.parts_by_symbol
+                        .entry(symbol_id)
+                        .or_default()
+                        .insert(number.id);
+                    self.symbols_by_part
+                        .entry(number.id)
+                        .or_default()
+                        .insert(symbol_id);
+                }
+            }
+        }
+    }
+
+    pub fn adjacent_parts(&self, symbol: &Symbol) -> impl Iterator<Item = &Number> {
+        self.parts_by_symbol
+            .get(&symbol.id)
+            .into_iter()
+            .flatten()
+            .map(|&id| &self.numbers[id])
+    }
+
+    pub fn adjacent_symbols(&self, number: &Number) -> impl Iterator<Item = &Symbol> {
+        self.symbols_by_part
+            .get(&number.id)
+            .into_iter()
+            .flatten()
+            .map(|&id| &self.symbols[id])
+    }
+
+    pub fn is_part(&self, number: &Number) -> bool {
+        self.symbols_by_part.contains_key(&number.id)
+    }
+
+    // Every number adjacent to at least one symbol, each yielded once
+    pub fn parts(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|number| self.is_part(number))
+    }
+
+    // Numbers adjacent to no symbol at all
+    pub fn loose_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|number| !self.is_part(number))
+    }
+
+    // Every number adjacent to a `symbol`, each yielded once even if it
+    // touches several of them
+    pub fn parts_adjacent_to(&self, symbol: char) -> impl Iterator<Item = &Number> {
+        self.parts().filter(move |number| {
+            self.adjacent_symbols(number)
+                .any(|adjacent| adjacent.char == symbol)
+        })
+    }
+
+    // `*` symbols touching exactly `part_count` parts
+    pub fn gears(&self, part_count: usize) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.char == '*')
+            .map(|symbol| (symbol, self.adjacent_parts(symbol).collect::<Vec<_>>()))
+            .filter(move |(_, parts)| parts.len() == part_count)
+    }
+
+    pub fn gear_ratios(&self, part_count: usize) -> impl Iterator<Item = u64> + '_ {
+        self.gears(part_count).map(|(_, parts)| {
+            parts
+                .into_iter()
+                .map(|number| number.value as u64)
+                .product()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    fn values<'a>(numbers: impl Iterator<Item = &'a Number>) -> Vec<u32> {
+        numbers.map(|number| number.value).collect()
+    }
+
+    #[test]
+    fn parse_spans() {
+        let schematic = Schematic::parse(EXAMPLE);
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(schematic.symbols.len(), 6);
+        assert_eq!(
+            schematic.numbers[1],
+            Number {
+                id: 1,
+                value: 114,
+                row: 0,
+                start: 5,
+                end: 8
+            }
+        );
+    }
+
+    #[test]
+    fn loose_numbers() {
+        let schematic = Schematic::parse(EXAMPLE);
+        assert_eq!(values(schematic.loose_numbers()), vec![114, 58]);
+    }
+
+    #[test]
+    fn parts_adjacent_to_star() {
+        let schematic = Schematic::parse(EXAMPLE);
+        assert_eq!(
+            values(schematic.parts_adjacent_to('*')),
+            vec![467, 35, 617, 755, 598]
+        );
+    }
+
+    #[test]
+    fn number_touching_two_symbols_counted_once() {
+        let schematic = Schematic::parse(
+            "*..
+12.
+..#",
+        );
+        let number = &schematic.numbers[0];
+        assert_eq!(schematic.adjacent_symbols(number).count(), 2);
+        assert_eq!(values(schematic.parts()), vec![12]);
+    }
+
+    #[test]
+    fn gears_with_exactly_n_parts() {
+        let schematic = Schematic::parse(EXAMPLE);
+        assert_eq!(
+            schematic.gear_ratios(2).collect::<Vec<_>>(),
+            vec![16345, 451490]
+        );
+        assert_eq!(
+            values(schematic.gears(1).flat_map(|(_, parts)| parts)),
+            vec![617]
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Schematic;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Kind {
+    Empty,
+    Part,
+    Loose,
+    Symbol,
+    Gear,
+}
+
+impl Kind {
+    fn ansi(&self) -> Option<&'static str> {
+        match self {
+            Kind::Empty => None,
+            Kind::Part => Some("\x1b[32m"),
+            Kind::Loose => Some("\x1b[31m"),
+            Kind::Symbol => Some("\x1b[33m"),
+            Kind::Gear => Some("\x1b[1;35m"),
+        }
+    }
+
+    fn class(&self) -> Option<&'static str> {
+        match self {
+            Kind::Empty => None,
+            Kind::Part => Some("part"),
+            Kind::Loose => Some("loose"),
+            Kind::Symbol => Some("symbol"),
+            Kind::Gear => Some("gear"),
+        }
+    }
+}
+
+const HTML_STYLE: &str = "body { background: #0f0f23; color: #666; }
+.part { color: #00cc00; }
+.loose { color: #ff4444; }
+.symbol { color: #ffff66; }
+.gear { color: #ff66ff; font-weight: bold; }";
+
+// Tags every character of `input` with what it is in `schematic`, which is
+// expected to be parsed from the same input
+pub fn classify(input: &str, schematic: &Schematic) -> Vec<Vec<(char, Kind)>> {
+    let gears: HashSet<usize> = schematic.gears(2).map(|(symbol, _)| symbol.id).collect();
+
+    let mut kinds: HashMap<(usize, usize), Kind> = HashMap::new();
+    for number in &schematic.numbers {
+        let kind = if schematic.is_part(number) {
+            Kind::Part
+        } else {
+            Kind::Loose
+        };
+        for col in number.start..number.end {
+            kinds.insert((number.row, col), kind);
+        }
+    }
+    for symbol in &schematic.symbols {
+        let kind = if gears.contains(&symbol.id) {
+            Kind::Gear
+        } else {
+            Kind::Symbol
+        };
+        kinds.insert((symbol.row, symbol.col), kind);
+    }
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.char_indices()
+                .map(|(col, c)| (c, *kinds.get(&(row, col)).unwrap_or(&Kind::Empty)))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn to_ansi(input: &str, schematic: &Schematic) -> String {
+    let mut out = String::new();
+    for line in classify(input, schematic) {
+        let mut current = Kind::Empty;
+        for (c, kind) in line {
+            if kind != current {
+                if current != Kind::Empty {
+                    out.push_str("\x1b[0m");
+                }
+                if let Some(colour) = kind.ansi() {
+                    out.push_str(colour);
+                }
+                current = kind;
+            }
+            out.push(c);
+        }
+        if current != Kind::Empty {
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub fn to_html(input: &str, schematic: &Schematic) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<style>\n{HTML_STYLE}\n</style>\n</head>\n<body>\n<pre>\n"
+    );
+    for line in classify(input, schematic) {
+        let mut current = Kind::Empty;
+        for (c, kind) in line {
+            if kind != current {
+                if current != Kind::Empty {
+                    out.push_str("</span>");
+                }
+                if let Some(class) = kind.class() {
+                    out.push_str(&format!("<span class=\"{class}\">"));
+                }
+                current = kind;
+            }
+            match c {
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '&' => out.push_str("&amp;"),
+                c => out.push(c),
+            }
+        }
+        if current != Kind::Empty {
+            out.push_str("</span>");
+        }
+        out.push('\n');
+    }
+    out.push_str("</pre>\n</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "12..5
+.*&..
+3....";
+
+    #[test]
+    fn classify_cells() {
+        let schematic = Schematic::parse(EXAMPLE);
+        let kinds: Vec<Vec<Kind>> = classify(EXAMPLE, &schematic)
+            .into_iter()
+            .map(|line| line.into_iter().map(|(_, kind)| kind).collect())
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                vec![
+                    Kind::Part,
+                    Kind::Part,
+                    Kind::Empty,
+                    Kind::Empty,
+                    Kind::Loose
+                ],
+                vec![
+                    Kind::Empty,
+                    Kind::Gear,
+                    Kind::Symbol,
+                    Kind::Empty,
+                    Kind::Empty
+                ],
+                vec![
+                    Kind::Part,
+                    Kind::Empty,
+                    Kind::Empty,
+                    Kind::Empty,
+                    Kind::Empty
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn ansi() {
+        let schematic = Schematic::parse(EXAMPLE);
+        assert_eq!(
+            to_ansi(EXAMPLE, &schematic),
+            "\x1b[32m12\x1b[0m..\x1b[31m5\x1b[0m
+.\x1b[1;35m*\x1b[0m\x1b[33m&\x1b[0m..
+\x1b[32m3\x1b[0m....
+"
+        );
+    }
+
+    #[test]
+    fn html_escapes_symbols() {
+        let schematic = Schematic::parse(EXAMPLE);
+        assert!(to_html(EXAMPLE, &schematic)
+            .contains(".<span class=\"gear\">*</span><span class=\"symbol\">&amp;</span>.."));
+    }
+}
@@ -0,0 +1,43 @@
+use day_04::{cascade, parse, validate};
+
+// Usage: cascade [input file]
+fn main() -> std::io::Result<()> {
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+    let (_, cards) = parse(&input).expect("valid scratchcards");
+
+    for issue in validate(&cards) {
+        println!("{issue}");
+    }
+
+    let reports = cascade(&cards);
+
+    println!(
+        "{:>6} | {:>7} | {:>8} | {:>10} | won from",
+        "card", "matches", "points", "copies"
+    );
+    println!("{}", "-".repeat(50));
+    for report in &reports {
+        let won_from = report
+            .won_from
+            .iter()
+            .map(|(id, copies)| format!("{id}x{copies}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!(
+            "{:>6} | {:>7} | {:>8} | {:>10} | {}",
+            report.id, report.matches, report.points, report.copies, won_from
+        );
+    }
+    println!("{}", "-".repeat(50));
+    println!(
+        "{:>6} | {:>7} | {:>8} | {:>10} |",
+        "total",
+        reports.iter().map(|report| report.matches).sum::<usize>(),
+        reports.iter().map(|report| report.points).sum::<usize>(),
+        reports.iter().map(|report| report.copies).sum::<usize>(),
+    );
+    Ok(())
+}
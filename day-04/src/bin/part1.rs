@@ -1,62 +1,24 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::{multispace1, u32},
-    combinator::map,
-    multi::separated_list1,
-    sequence::{delimited, preceded, tuple},
-    IResult,
-};
-
-#[derive(Debug)]
-struct Card {
-    id: u32,
-    winning_numbers: Vec<u32>,
-    numbers: Vec<u32>,
-}
+use day_04::parse_checked;
 
 fn process(input: &str) -> String {
-    let (_, cards) = parse(input).unwrap();
-    dbg!(&cards);
+    let (cards, warnings) = match parse_checked(input) {
+        Ok(checked) => checked,
+        Err(issues) => {
+            issues.iter().for_each(|issue| eprintln!("{issue}"));
+            std::process::exit(1);
+        }
+    };
+    for warning in warnings {
+        eprintln!("{warning}");
+    }
 
     cards
-        .into_iter()
-        .map(|card| {
-            dbg!(&card.id);
-            let wins = card
-                .winning_numbers
-                .iter()
-                .filter(|num| card.numbers.contains(num))
-                .count();
-            match wins {
-                0 | 1 => wins,
-                n => dbg!(2_usize.pow(n as u32 - 1)),
-            }
-        })
+        .iter()
+        .map(|card| card.points())
         .sum::<usize>()
         .to_string()
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<Card>> {
-    separated_list1(
-        multispace1,
-        map(
-            tuple((
-                delimited(tuple((tag("Card"), multispace1)), u32, tag(":")),
-                preceded(multispace1, separated_list1(multispace1, u32)),
-                preceded(
-                    tuple((multispace1, tag("|"), multispace1)),
-                    separated_list1(multispace1, u32),
-                ),
-            )),
-            |(id, winning_numbers, numbers)| Card {
-                id,
-                winning_numbers,
-                numbers,
-            },
-        ),
-    )(input)
-}
-
 fn main() {
     println!("Output: {}", process(include_str!("./input.txt")));
 }
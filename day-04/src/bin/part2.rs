@@ -1,73 +1,23 @@
-use std::collections::BTreeMap;
-
-use nom::{
-    bytes::complete::tag,
-    character::complete::{multispace1, u32},
-    combinator::map,
-    multi::separated_list1,
-    sequence::{delimited, preceded, tuple},
-    IResult,
-};
-
-#[derive(Debug)]
-struct Card {
-    id: u32,
-    winning_numbers: Vec<u32>,
-    numbers: Vec<u32>,
-}
+use day_04::{count_copies, parse_checked, Card};
 
 fn process(input: &str) -> String {
-    let (_, cards) = parse(input).unwrap();
-    dbg!(&cards);
-
-    let all_wins: Vec<usize> = cards.into_iter().map(count_wins).collect();
-
-    let map = extract_card_copies(all_wins);
-
-    map.values().sum::<usize>().to_string()
-}
-
-fn count_wins(card: Card) -> usize {
-    card.winning_numbers
-        .iter()
-        .filter(|num| card.numbers.contains(num))
-        .count()
-}
-
-fn extract_card_copies(all_wins: Vec<usize>) -> BTreeMap<usize, usize> {
-    let mut map: BTreeMap<usize, usize> = (0..all_wins.len()).map(|i| (i, 1)).collect();
-    for (i, wins) in all_wins.into_iter().enumerate() {
-        let repeats = *map.get(&i).unwrap_or(&1);
-        for next_card in i + 1..=i + wins {
-            if let Some(repeat_num) = map.get_mut(&next_card) {
-                *repeat_num += repeats;
-            } else {
-                map.insert(next_card, repeats);
-            };
+    let (cards, warnings) = match parse_checked(input) {
+        Ok(checked) => checked,
+        Err(issues) => {
+            issues.iter().for_each(|issue| eprintln!("{issue}"));
+            std::process::exit(1);
         }
+    };
+    for warning in warnings {
+        eprintln!("{warning}");
     }
-    map
-}
 
-fn parse(input: &str) -> IResult<&str, Vec<Card>> {
-    separated_list1(
-        multispace1,
-        map(
-            tuple((
-                delimited(tuple((tag("Card"), multispace1)), u32, tag(":")),
-                preceded(multispace1, separated_list1(multispace1, u32)),
-                preceded(
-                    tuple((multispace1, tag("|"), multispace1)),
-                    separated_list1(multispace1, u32),
-                ),
-            )),
-            |(id, winning_numbers, numbers)| Card {
-                id,
-                winning_numbers,
-                numbers,
-            },
-        ),
-    )(input)
+    let all_wins: Vec<usize> = cards.iter().map(Card::count_wins).collect();
+
+    count_copies(&all_wins)
+        .into_iter()
+        .sum::<usize>()
+        .to_string()
 }
 
 fn main() {
@@ -77,6 +27,7 @@ fn main() {
 #[cfg(test)]
 mod test {
     use super::*;
+    use day_04::parse;
 
     const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
@@ -96,13 +47,10 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
         let all_wins: Vec<usize> = parse(EXAMPLE)
             .unwrap()
             .1
-            .into_iter()
-            .map(count_wins)
+            .iter()
+            .map(Card::count_wins)
             .collect();
 
-        assert_eq!(
-            extract_card_copies(all_wins),
-            BTreeMap::from([(0, 1), (1, 2), (2, 4), (3, 8), (4, 14), (5, 1)])
-        )
+        assert_eq!(count_copies(&all_wins), vec![1, 2, 4, 8, 14, 1])
     }
 }
@@ -0,0 +1,376 @@
+use std::{collections::HashSet, fmt};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{multispace1, u32},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+#[derive(Debug)]
+pub struct Card {
+    pub id: u32,
+    pub winning_numbers: Vec<u32>,
+    pub numbers: Vec<u32>,
+}
+
+impl Card {
+    pub fn count_wins(&self) -> usize {
+        self.winning_numbers
+            .iter()
+            .filter(|num| self.numbers.contains(num))
+            .count()
+    }
+
+    pub fn points(&self) -> usize {
+        match self.count_wins() {
+            0 => 0,
+            n => 2_usize.pow(n as u32 - 1),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CardReport {
+    pub id: u32,
+    pub matches: usize,
+    pub points: usize,
+    // (card id, copies) of every earlier card that won a copy of this one
+    pub won_from: Vec<(u32, usize)>,
+    pub copies: usize,
+}
+
+// Number of copies held of each card, including the original, using a
+// running difference so every card is visited once
+pub fn count_copies(all_wins: &[usize]) -> Vec<usize> {
+    let mut copies = vec![0; all_wins.len()];
+    let mut difference = vec![0isize; all_wins.len() + 1];
+    let mut running = 0isize;
+    for (i, &wins) in all_wins.iter().enumerate() {
+        running += difference[i];
+        copies[i] = (1 + running) as usize;
+
+        let end = (i + wins + 1).min(all_wins.len());
+        if i + 1 < end {
+            difference[i + 1] += copies[i] as isize;
+            difference[end] -= copies[i] as isize;
+        }
+    }
+    copies
+}
+
+pub fn cascade(cards: &[Card]) -> Vec<CardReport> {
+    let mut reports: Vec<CardReport> = cards
+        .iter()
+        .map(|card| CardReport {
+            id: card.id,
+            matches: card.count_wins(),
+            points: card.points(),
+            won_from: vec![],
+            copies: 1,
+        })
+        .collect();
+
+    for i in 0..reports.len() {
+        let (id, matches, copies) = (reports[i].id, reports[i].matches, reports[i].copies);
+        for next in reports.iter_mut().skip(i + 1).take(matches) {
+            next.won_from.push((id, copies));
+            next.copies += copies;
+        }
+    }
+    reports
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Issue {
+    // A line that isn't a card, stopping the cards from being read any
+    // further
+    Unparsable {
+        line: usize,
+        text: String,
+    },
+    DuplicateWinningNumber {
+        card: u32,
+        number: u32,
+    },
+    DuplicateNumber {
+        card: u32,
+        number: u32,
+    },
+    OutOfOrder {
+        card: u32,
+        expected: u32,
+    },
+    WinsPastEnd {
+        card: u32,
+        matches: usize,
+        remaining: usize,
+    },
+}
+
+impl Issue {
+    // Errors change the answer, warnings are only suspicious
+    pub fn is_error(&self) -> bool {
+        match self {
+            Issue::Unparsable { .. }
+            | Issue::DuplicateWinningNumber { .. }
+            | Issue::OutOfOrder { .. } => true,
+            Issue::DuplicateNumber { .. } | Issue::WinsPastEnd { .. } => false,
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = if self.is_error() { "error" } else { "warning" };
+        match self {
+            Issue::Unparsable { line, text } => {
+                write!(f, "{level}: line {line} isn't a scratchcard: {text:?}")
+            }
+            Issue::DuplicateWinningNumber { card, number } => {
+                write!(
+                    f,
+                    "{level}: card {card} lists winning number {number} more than once"
+                )
+            }
+            Issue::DuplicateNumber { card, number } => {
+                write!(f, "{level}: card {card} has number {number} more than once")
+            }
+            Issue::OutOfOrder { card, expected } => {
+                write!(
+                    f,
+                    "{level}: card {card} found where card {expected} was expected"
+                )
+            }
+            Issue::WinsPastEnd {
+                card,
+                matches,
+                remaining,
+            } => write!(
+                f,
+                "{level}: card {card} wins {matches} copies but only {remaining} cards follow it"
+            ),
+        }
+    }
+}
+
+fn duplicates(numbers: &[u32]) -> Vec<u32> {
+    let mut seen = HashSet::new();
+    let mut reported = HashSet::new();
+    numbers
+        .iter()
+        .filter(|&&number| !seen.insert(number) && reported.insert(number))
+        .copied()
+        .collect()
+}
+
+pub fn validate(cards: &[Card]) -> Vec<Issue> {
+    let mut issues = vec![];
+    let mut expected = 1;
+    for (i, card) in cards.iter().enumerate() {
+        if card.id != expected {
+            issues.push(Issue::OutOfOrder {
+                card: card.id,
+                expected,
+            });
+        }
+        expected = card.id + 1;
+
+        issues.extend(duplicates(&card.winning_numbers).into_iter().map(|number| {
+            Issue::DuplicateWinningNumber {
+                card: card.id,
+                number,
+            }
+        }));
+        issues.extend(
+            duplicates(&card.numbers)
+                .into_iter()
+                .map(|number| Issue::DuplicateNumber {
+                    card: card.id,
+                    number,
+                }),
+        );
+
+        let matches = card.count_wins();
+        let remaining = cards.len() - i - 1;
+        if matches > remaining {
+            issues.push(Issue::WinsPastEnd {
+                card: card.id,
+                matches,
+                remaining,
+            });
+        }
+    }
+    issues
+}
+
+// Parses and validates the cards, returning the warnings alongside the cards
+// or every issue found if any of them is an error
+pub fn parse_checked(input: &str) -> Result<(Vec<Card>, Vec<Issue>), Vec<Issue>> {
+    let unparsable = |rest: &str| {
+        let line = input[..input.len() - rest.len()].matches('\n').count() + 1;
+        let text = input
+            .lines()
+            .nth(line - 1)
+            .unwrap_or(rest)
+            .trim()
+            .to_string();
+        vec![Issue::Unparsable { line, text }]
+    };
+    let (rest, cards) = match parse(input) {
+        Ok(parsed) => parsed,
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            return Err(unparsable(error.input))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers to never be incomplete"),
+    };
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        return Err(unparsable(rest));
+    }
+    let issues = validate(&cards);
+    if issues.iter().any(Issue::is_error) {
+        Err(issues)
+    } else {
+        Ok((cards, issues))
+    }
+}
+
+pub fn parse(input: &str) -> IResult<&str, Vec<Card>> {
+    separated_list1(
+        multispace1,
+        map(
+            tuple((
+                delimited(tuple((tag("Card"), multispace1)), u32, tag(":")),
+                preceded(multispace1, separated_list1(multispace1, u32)),
+                preceded(
+                    tuple((multispace1, tag("|"), multispace1)),
+                    separated_list1(multispace1, u32),
+                ),
+            )),
+            |(id, winning_numbers, numbers)| Card {
+                id,
+                winning_numbers,
+                numbers,
+            },
+        ),
+    )(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_validate_example() {
+        assert!(parse_checked(EXAMPLE).is_ok_and(|(_, warnings)| warnings.is_empty()));
+    }
+
+    #[test]
+    fn test_validate_issues() {
+        let input = "Card 1: 1 2 2 | 2 3 3
+Card 3: 4 5 | 4 5
+Card 4: 6 | 7";
+        let issues = parse_checked(input).unwrap_err();
+
+        assert_eq!(
+            issues,
+            vec![
+                Issue::DuplicateWinningNumber { card: 1, number: 2 },
+                Issue::DuplicateNumber { card: 1, number: 3 },
+                Issue::OutOfOrder {
+                    card: 3,
+                    expected: 2
+                },
+                Issue::WinsPastEnd {
+                    card: 3,
+                    matches: 2,
+                    remaining: 1
+                },
+            ]
+        );
+        assert_eq!(
+            issues[3].to_string(),
+            "warning: card 3 wins 2 copies but only 1 cards follow it"
+        );
+    }
+
+    #[test]
+    fn test_unparsable() {
+        assert_eq!(
+            parse_checked("Card 1: 1 | 1\nCard 2: x | 1\nCard 3: 2 | 2").err(),
+            Some(vec![Issue::Unparsable {
+                line: 2,
+                text: "Card 2: x | 1".to_string()
+            }])
+        );
+        assert_eq!(
+            parse_checked("Cord 1: 1 | 1").err(),
+            Some(vec![Issue::Unparsable {
+                line: 1,
+                text: "Cord 1: 1 | 1".to_string()
+            }])
+        );
+        assert!(parse_checked(&format!("{EXAMPLE}\n")).is_ok());
+    }
+
+    #[test]
+    fn test_warnings_only() {
+        let (cards, warnings) = parse_checked("Card 1: 1 | 1 1").unwrap();
+
+        assert_eq!(cards.len(), 1);
+        assert_eq!(
+            warnings,
+            vec![
+                Issue::DuplicateNumber { card: 1, number: 1 },
+                Issue::WinsPastEnd {
+                    card: 1,
+                    matches: 1,
+                    remaining: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_count_copies_past_end() {
+        assert_eq!(count_copies(&[3, 1]), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_cascade() {
+        let (_, cards) = parse(EXAMPLE).unwrap();
+        let reports = cascade(&cards);
+
+        assert_eq!(
+            reports[3],
+            CardReport {
+                id: 4,
+                matches: 1,
+                points: 1,
+                won_from: vec![(1, 1), (2, 2), (3, 4)],
+                copies: 8,
+            }
+        );
+        assert_eq!(
+            reports.iter().map(|report| report.points).sum::<usize>(),
+            13
+        );
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| report.copies)
+                .collect::<Vec<_>>(),
+            count_copies(&cards.iter().map(Card::count_wins).collect::<Vec<_>>())
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt;
+
+use crate::{differences, Error, History};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Shape {
+    Constant,
+    Linear,
+    Quadratic,
+    Cubic,
+    Polynomial(usize),
+}
+
+impl From<usize> for Shape {
+    fn from(degree: usize) -> Self {
+        match degree {
+            0 => Shape::Constant,
+            1 => Shape::Linear,
+            2 => Shape::Quadratic,
+            3 => Shape::Cubic,
+            n => Shape::Polynomial(n),
+        }
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shape::Constant => write!(f, "constant"),
+            Shape::Linear => write!(f, "linear"),
+            Shape::Quadratic => write!(f, "quadratic"),
+            Shape::Cubic => write!(f, "cubic"),
+            Shape::Polynomial(degree) => write!(f, "degree {degree}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Outlier {
+    pub index: usize,
+    pub value: i128,
+    pub corrected: i128,
+    // Degree of the sequence once `corrected` replaces `value`
+    pub degree: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Analysis {
+    // None when the difference table never reaches a row of zeros
+    pub degree: Option<usize>,
+    pub error: Option<Error>,
+    pub outliers: Vec<Outlier>,
+}
+
+impl Analysis {
+    pub fn shape(&self) -> Option<Shape> {
+        self.degree.map(Shape::from)
+    }
+}
+
+// Value that `values[index]` must take for the sequence to be generated by a
+// polynomial of `degree`, if any. Needs at least two rows of differences to
+// check against, otherwise any value would fit.
+fn correction(values: &[i128], index: usize, degree: usize) -> Option<i128> {
+    let order = degree + 1;
+    if values.len() < order + 2 {
+        return None;
+    }
+
+    // The differences are linear in the value at `index`, so track the rest
+    // of the sequence and a unit impulse at `index` separately
+    let mut rest = values.to_vec();
+    rest[index] = 0;
+    let mut impulse = vec![0; values.len()];
+    impulse[index] = 1;
+    for _ in 0..order {
+        rest = differences(&rest).ok()?;
+        impulse = differences(&impulse).ok()?;
+    }
+
+    let mut corrected = None;
+    for (r, s) in rest.into_iter().zip(impulse) {
+        if s == 0 {
+            if r != 0 {
+                return None;
+            }
+            continue;
+        }
+        if r % s != 0 {
+            return None;
+        }
+        let value = -(r / s);
+        match corrected {
+            Some(corrected) if corrected != value => return None,
+            _ => corrected = Some(value),
+        }
+    }
+    corrected
+}
+
+pub fn analyse(values: &[i128]) -> Analysis {
+    let (degree, error) = match History::new(values.to_vec()) {
+        Ok(history) => (Some(history.degree()), None),
+        Err(error) => (None, Some(error)),
+    };
+
+    let max_degree = degree.unwrap_or(values.len().saturating_sub(1));
+    let outliers = (0..max_degree)
+        .map(|degree| {
+            (0..values.len())
+                .filter_map(|index| {
+                    let corrected = correction(values, index, degree)?;
+                    (corrected != values[index]).then_some(Outlier {
+                        index,
+                        value: values[index],
+                        corrected,
+                        degree,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .find(|outliers| !outliers.is_empty())
+        .unwrap_or_default();
+
+    Analysis {
+        degree,
+        error,
+        outliers,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shapes() {
+        let shapes: Vec<_> = [
+            vec![0, 3, 6, 9, 12, 15],
+            vec![1, 3, 6, 10, 15, 21],
+            vec![10, 13, 16, 21, 30, 45],
+            vec![4, 4, 4],
+        ]
+        .iter()
+        .map(|values| analyse(values).shape())
+        .collect();
+        assert_eq!(
+            shapes,
+            vec![
+                Some(Shape::Linear),
+                Some(Shape::Quadratic),
+                Some(Shape::Cubic),
+                Some(Shape::Constant)
+            ]
+        );
+    }
+
+    #[test]
+    fn single_outlier() {
+        let analysis = analyse(&[0, 3, 6, 10, 12, 15]);
+        assert_eq!(
+            analysis.outliers,
+            vec![Outlier {
+                index: 3,
+                value: 10,
+                corrected: 9,
+                degree: 1
+            }]
+        );
+        assert!(analyse(&[0, 3, 6, 9, 12, 15]).outliers.is_empty());
+    }
+
+    #[test]
+    fn outlier_in_non_converging() {
+        let analysis = analyse(&[1, 2, 3, 4, 5, 6, 100]);
+        assert_eq!(analysis.shape(), None);
+        assert_eq!(analysis.error, Some(Error::NoZeroRow));
+        assert_eq!(
+            analysis.outliers,
+            vec![Outlier {
+                index: 6,
+                value: 100,
+                corrected: 7,
+                degree: 1
+            }]
+        );
+    }
+}
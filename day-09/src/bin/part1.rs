@@ -1,43 +1,14 @@
-use itertools::Itertools;
+use day_09::{parse, History};
 
 fn process(input: &str) -> String {
-    let histories: Vec<Vec<i32>> = input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|n| n.parse().expect("input to be number"))
-                .collect()
-        })
-        .collect();
-
-    fn find_next_in_sequence(list: Vec<i32>) -> i32 {
-        let mut only_zeros = true;
-        let differences = list
-            .iter()
-            .tuple_windows()
-            .map(|(a, b)| b - a)
-            .inspect(|n| {
-                if n != &0 {
-                    only_zeros = false;
-                }
-            })
-            .collect_vec();
-        dbg!(&list, &differences, only_zeros);
-        let last = *list.last().expect("to have elements in difference array");
-        if !only_zeros {
-            let next = find_next_in_sequence(differences);
-            dbg!(last, next);
-            last + next
-        } else {
-            last
-        }
-    }
-
-    histories
+    parse(input)
         .into_iter()
-        .map(find_next_in_sequence)
-        .inspect(|next| println!("{next}"))
-        .sum::<i32>()
+        .map(|values| {
+            History::new(values)
+                .and_then(|history| history.next(1))
+                .expect("history to extrapolate")
+        })
+        .sum::<i128>()
         .to_string()
 }
 
@@ -0,0 +1,35 @@
+use day_09::{
+    analysis::{analyse, Shape},
+    parse, History,
+};
+
+// Usage: report [input file]
+fn main() -> std::io::Result<()> {
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+
+    for (line, values) in parse(&input).into_iter().enumerate() {
+        let analysis = analyse(&values);
+        let shape = match analysis.shape() {
+            Some(shape) => shape.to_string(),
+            None => format!("{:?}", analysis.error.expect("error without degree")),
+        };
+        let extrapolated = History::new(values)
+            .and_then(|history| Ok((history.previous(1)?, history.next(1)?)))
+            .map(|(previous, next)| format!("{previous} .. {next}"))
+            .unwrap_or_default();
+        println!("{:>4}: {shape:<12} {extrapolated}", line + 1);
+        for outlier in analysis.outliers {
+            println!(
+                "      reading {} is {} but {} would make it {}",
+                outlier.index,
+                outlier.value,
+                outlier.corrected,
+                Shape::from(outlier.degree)
+            );
+        }
+    }
+    Ok(())
+}
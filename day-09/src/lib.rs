@@ -0,0 +1,185 @@
+use itertools::Itertools;
+use num::{rational::Ratio, CheckedAdd, CheckedMul, One, Zero};
+
+pub mod analysis;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Error {
+    Empty,
+    Overflow,
+    // The difference table ran out of values before reaching a row of zeros
+    NoZeroRow,
+}
+
+pub(crate) fn differences(row: &[i128]) -> Result<Vec<i128>, Error> {
+    row.iter()
+        .tuple_windows()
+        .map(|(a, b)| b.checked_sub(*a).ok_or(Error::Overflow))
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct History {
+    pub values: Vec<i128>,
+    // Difference table, ending with the first row that is all zeros
+    rows: Vec<Vec<i128>>,
+}
+
+impl History {
+    pub fn new(values: Vec<i128>) -> Result<Self, Error> {
+        if values.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let mut rows = vec![values.clone()];
+        loop {
+            let last = rows.last().expect("at least one row");
+            if last.iter().all(|n| *n == 0) {
+                break;
+            }
+            if last.len() == 1 {
+                return Err(Error::NoZeroRow);
+            }
+            rows.push(differences(last)?);
+        }
+
+        Ok(History { values, rows })
+    }
+
+    pub fn rows(&self) -> &[Vec<i128>] {
+        &self.rows
+    }
+
+    pub fn degree(&self) -> usize {
+        self.rows.len().saturating_sub(2)
+    }
+
+    // Value of the generating polynomial at `index`, where 0 is the first
+    // reading, using Newton's forward difference formula
+    pub fn value_at(&self, index: i128) -> Result<i128, Error> {
+        let mut binomial: i128 = 1;
+        let mut value: i128 = 0;
+        for (j, row) in self.rows.iter().enumerate() {
+            let term = binomial.checked_mul(row[0]).ok_or(Error::Overflow)?;
+            value = value.checked_add(term).ok_or(Error::Overflow)?;
+
+            if j + 1 < self.rows.len() {
+                let j = j as i128;
+                binomial = binomial.checked_mul(index - j).ok_or(Error::Overflow)? / (j + 1);
+            }
+        }
+        Ok(value)
+    }
+
+    // Value `steps` readings after the last one
+    pub fn next(&self, steps: u64) -> Result<i128, Error> {
+        self.value_at(self.values.len() as i128 - 1 + steps as i128)
+    }
+
+    // Value `steps` readings before the first one
+    pub fn previous(&self, steps: u64) -> Result<i128, Error> {
+        self.value_at(-(steps as i128))
+    }
+
+    // Coefficients of the generating polynomial in increasing powers of the
+    // reading index
+    pub fn coefficients(&self) -> Result<Vec<Ratio<i128>>, Error> {
+        let mut coefficients = vec![Ratio::zero(); self.degree() + 1];
+        // x(x - 1)...(x - j + 1) / j!
+        let mut falling = vec![Ratio::one()];
+        for (j, row) in self.rows.iter().enumerate() {
+            let difference = Ratio::from_integer(row[0]);
+            for (coefficient, factor) in coefficients.iter_mut().zip(&falling) {
+                *coefficient = difference
+                    .checked_mul(factor)
+                    .and_then(|term| coefficient.checked_add(&term))
+                    .ok_or(Error::Overflow)?;
+            }
+
+            let j = j as i128;
+            let mut next = vec![Ratio::zero(); falling.len() + 1];
+            for (power, factor) in falling.iter().enumerate() {
+                let factor = factor / (j + 1);
+                next[power + 1] = next[power + 1]
+                    .checked_add(&factor)
+                    .ok_or(Error::Overflow)?;
+                next[power] = factor
+                    .checked_mul(&Ratio::from_integer(-j))
+                    .and_then(|term| next[power].checked_add(&term))
+                    .ok_or(Error::Overflow)?;
+            }
+            falling = next;
+        }
+        Ok(coefficients)
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Vec<i128>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|n| n.parse().expect("input to be number"))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn history(values: &[i128]) -> History {
+        History::new(values.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn extrapolate_both_directions() {
+        let history = history(&[10, 13, 16, 21, 30, 45]);
+        assert_eq!(history.next(1), Ok(68));
+        assert_eq!(history.next(2), Ok(101));
+        assert_eq!(history.previous(1), Ok(5));
+        assert_eq!(history.previous(2), Ok(-4));
+        assert_eq!(history.value_at(3), Ok(21));
+    }
+
+    #[test]
+    fn coefficients() {
+        // (x + 1)(x + 2) / 2
+        assert_eq!(
+            history(&[1, 3, 6, 10, 15, 21]).coefficients(),
+            Ok(vec![
+                Ratio::from_integer(1),
+                Ratio::new(3, 2),
+                Ratio::new(1, 2)
+            ])
+        );
+        assert_eq!(history(&[0, 0, 0]).coefficients(), Ok(vec![Ratio::zero()]));
+    }
+
+    #[test]
+    fn degree() {
+        assert_eq!(history(&[7, 7, 7]).degree(), 0);
+        assert_eq!(history(&[0, 3, 6, 9]).degree(), 1);
+        assert_eq!(history(&[1, 3, 6, 10, 15, 21]).degree(), 2);
+    }
+
+    #[test]
+    fn no_zero_row() {
+        assert_eq!(History::new(vec![1, 2, 4]).unwrap_err(), Error::NoZeroRow);
+        assert_eq!(History::new(vec![]).unwrap_err(), Error::Empty);
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            History::new(vec![i128::MIN, i128::MAX]).unwrap_err(),
+            Error::Overflow
+        );
+        let third = i128::MAX / 3;
+        assert_eq!(
+            history(&[0, third, 2 * third]).next(2),
+            Err(Error::Overflow)
+        );
+    }
+}
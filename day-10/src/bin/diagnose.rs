@@ -0,0 +1,29 @@
+use day_10::Maze;
+
+// Usage: diagnose [input file]
+fn main() -> std::io::Result<()> {
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+    let maze = Maze::parse(&input);
+
+    println!("start candidates: {:?}", maze.start_candidates());
+    let Some(main_loop) = maze.find_loop() else {
+        println!("no candidate closes a loop");
+        return Ok(());
+    };
+    println!(
+        "start is {:?}, loop of {} tiles",
+        main_loop.start_pipe,
+        main_loop.len()
+    );
+
+    let diagnostics = maze.diagnose(&main_loop);
+    println!("{} pipes not on the loop", diagnostics.off_loop.len());
+    println!("{} dead ends:", diagnostics.dead_ends.len());
+    for (pos, direction) in diagnostics.dead_ends {
+        println!("  {},{} {direction:?}", pos.x, pos.y);
+    }
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use day_10::{render, Maze};
+
+// Usage: render [input file] [--out <text file>]
+fn main() -> std::io::Result<()> {
+    let mut input_path = None;
+    let mut out_path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => out_path = args.next(),
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let input = match input_path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+    let maze = Maze::parse(&input);
+    let main_loop = maze.find_loop().expect("start to be part of a loop");
+
+    match out_path {
+        Some(path) => std::fs::write(path, render::to_text(&maze, &main_loop)),
+        None => {
+            print!("{}", render::to_ansi(&maze, &main_loop));
+            Ok(())
+        }
+    }
+}
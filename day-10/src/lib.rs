@@ -0,0 +1,384 @@
+use std::collections::{HashMap, HashSet};
+
+use glam::{ivec2, IVec2};
+use itertools::Itertools;
+
+pub mod render;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    NE90,
+    NW90,
+    SE90,
+    SW90,
+    Start,
+}
+
+impl Pipe {
+    // Every pipe the start tile could stand in for
+    pub const SHAPES: [Pipe; 6] = [
+        Pipe::Vertical,
+        Pipe::Horizontal,
+        Pipe::NE90,
+        Pipe::NW90,
+        Pipe::SE90,
+        Pipe::SW90,
+    ];
+
+    pub fn connections(&self) -> &'static [Direction] {
+        match self {
+            Pipe::Vertical => &[Direction::North, Direction::South],
+            Pipe::Horizontal => &[Direction::West, Direction::East],
+            Pipe::NE90 => &[Direction::North, Direction::East],
+            Pipe::NW90 => &[Direction::North, Direction::West],
+            Pipe::SE90 => &[Direction::South, Direction::East],
+            Pipe::SW90 => &[Direction::South, Direction::West],
+            Pipe::Start => &Direction::ALL,
+        }
+    }
+
+    pub fn connects_to(&self, direction: &Direction) -> bool {
+        self.connections().contains(direction)
+    }
+
+    // Direction to leave this pipe in after entering it heading `from`
+    pub fn next_direction(&self, from: &Direction) -> Option<Direction> {
+        let in_direction = from.opposite();
+        if *self == Pipe::Start || !self.connects_to(&in_direction) {
+            return None;
+        }
+        self.connections()
+            .iter()
+            .find(|connection| **connection != in_direction)
+            .copied()
+    }
+
+    pub fn from_connections(a: Direction, b: Direction) -> Option<Pipe> {
+        Pipe::SHAPES
+            .into_iter()
+            .find(|pipe| a != b && pipe.connects_to(&a) && pipe.connects_to(&b))
+    }
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '|' => Pipe::Vertical,
+            '-' => Pipe::Horizontal,
+            'L' => Pipe::NE90,
+            'J' => Pipe::NW90,
+            '7' => Pipe::SW90,
+            'F' => Pipe::SE90,
+            'S' => Pipe::Start,
+            _ => return Err(()),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn right(&self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::South => Direction::West,
+            Direction::East => Direction::South,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn left(&self) -> Self {
+        self.right().opposite()
+    }
+
+    pub fn get_offset(&self) -> IVec2 {
+        match self {
+            Direction::North => ivec2(0, -1),
+            Direction::East => ivec2(1, 0),
+            Direction::South => ivec2(0, 1),
+            Direction::West => ivec2(-1, 0),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    pub tiles: Vec<Vec<Option<Pipe>>>,
+    pub start: IVec2,
+}
+
+impl Maze {
+    pub fn parse(input: &str) -> Self {
+        let tiles: Vec<Vec<Option<Pipe>>> = input
+            .lines()
+            .map(|line| line.chars().map(|c| c.try_into().ok()).collect())
+            .collect();
+
+        let start = tiles
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| {
+                row.iter()
+                    .position(|pipe| pipe == &Some(Pipe::Start))
+                    .map(|x| ivec2(x as i32, y as i32))
+            })
+            .expect("start pipe to exist");
+
+        Maze { tiles, start }
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<Pipe> {
+        if pos.x < 0 || pos.y < 0 {
+            return None;
+        }
+        *self.tiles.get(pos.y as usize)?.get(pos.x as usize)?
+    }
+
+    // Follows the pipes from the start heading `direction`, returning the
+    // visited tiles and the heading when arriving back at the start
+    fn walk(&self, direction: Direction) -> Option<(Vec<IVec2>, Direction)> {
+        let mut tiles = vec![self.start];
+        let mut pos = self.start + direction.get_offset();
+        let mut heading = direction;
+        loop {
+            let pipe = self.get(pos)?;
+            if pipe == Pipe::Start {
+                return Some((tiles, heading));
+            }
+            heading = pipe.next_direction(&heading)?;
+            tiles.push(pos);
+            pos += heading.get_offset();
+        }
+    }
+
+    // Shapes whose connections both lead into a pipe connecting back to the
+    // start
+    pub fn start_candidates(&self) -> Vec<Pipe> {
+        Pipe::SHAPES
+            .into_iter()
+            .filter(|shape| {
+                shape.connections().iter().all(|direction| {
+                    self.get(self.start + direction.get_offset())
+                        .is_some_and(|pipe| pipe.connects_to(&direction.opposite()))
+                })
+            })
+            .collect()
+    }
+
+    // Tries every candidate shape for the start tile, keeping the first one
+    // where leaving through one connection comes back in through the other
+    pub fn find_loop(&self) -> Option<Loop> {
+        self.start_candidates().into_iter().find_map(|shape| {
+            let [out, back] = shape.connections() else {
+                unreachable!("shapes to have two connections");
+            };
+            let (tiles, heading) = self.walk(*out)?;
+            (heading.opposite() == *back).then_some(Loop {
+                tiles,
+                start_pipe: shape,
+            })
+        })
+    }
+
+    pub fn diagnose(&self, main_loop: &Loop) -> Diagnostics {
+        let loop_tiles: HashSet<IVec2> = main_loop.tiles.iter().copied().collect();
+        let resolve = |pos: IVec2| match self.get(pos)? {
+            Pipe::Start => Some(main_loop.start_pipe),
+            pipe => Some(pipe),
+        };
+
+        let mut diagnostics = Diagnostics::default();
+        for (y, row) in self.tiles.iter().enumerate() {
+            for x in 0..row.len() {
+                let pos = ivec2(x as i32, y as i32);
+                let Some(pipe) = resolve(pos) else {
+                    continue;
+                };
+                if !loop_tiles.contains(&pos) {
+                    diagnostics.off_loop.push(pos);
+                }
+                diagnostics.dead_ends.extend(
+                    pipe.connections()
+                        .iter()
+                        .filter(|direction| {
+                            !resolve(pos + direction.get_offset())
+                                .is_some_and(|next| next.connects_to(&direction.opposite()))
+                        })
+                        .map(|direction| (pos, *direction)),
+                );
+            }
+        }
+        diagnostics
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    // Pipe ends that do not lead into a pipe connecting back
+    pub dead_ends: Vec<(IVec2, Direction)>,
+    // Pipes that are not part of the main loop
+    pub off_loop: Vec<IVec2>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Loop {
+    // Every tile of the loop in walking order, beginning at the start
+    pub tiles: Vec<IVec2>,
+    pub start_pipe: Pipe,
+}
+
+impl Loop {
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    // Tiles where the loop turns, in walking order
+    pub fn vertices(&self) -> Vec<IVec2> {
+        let len = self.tiles.len();
+        (0..len)
+            .filter(|&i| {
+                let previous = self.tiles[(i + len - 1) % len];
+                let next = self.tiles[(i + 1) % len];
+                self.tiles[i] - previous != next - self.tiles[i]
+            })
+            .map(|i| self.tiles[i])
+            .collect()
+    }
+
+    // Steps from the start to every loop tile, going whichever way round is
+    // shorter
+    pub fn distances(&self) -> HashMap<IVec2, usize> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| (*pos, i.min(self.len() - i)))
+            .collect()
+    }
+
+    pub fn farthest_distance(&self) -> usize {
+        self.len() / 2
+    }
+
+    // Shoelace formula over the tile centres
+    pub fn twice_area(&self) -> i64 {
+        self.vertices()
+            .iter()
+            .circular_tuple_windows()
+            .map(|(a, b)| a.x as i64 * b.y as i64 - b.x as i64 * a.y as i64)
+            .sum::<i64>()
+            .abs()
+    }
+
+    // Pick's theorem, A = i + b / 2 - 1, with every loop tile on the boundary
+    pub fn enclosed_tiles(&self) -> usize {
+        (self.twice_area() - self.len() as i64 + 2) as usize / 2
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn start_pipe_from_connections() {
+        assert_eq!(
+            Pipe::from_connections(Direction::North, Direction::South),
+            Some(Pipe::Vertical)
+        );
+        assert_eq!(
+            Pipe::from_connections(Direction::West, Direction::East),
+            Some(Pipe::Horizontal)
+        );
+        assert_eq!(
+            Pipe::from_connections(Direction::West, Direction::South),
+            Some(Pipe::SW90)
+        );
+        assert_eq!(
+            Pipe::from_connections(Direction::West, Direction::West),
+            None
+        );
+    }
+
+    #[test]
+    fn start_with_three_connecting_neighbours() {
+        let maze = Maze::parse(
+            "..|..
+.FS7.
+.|.|.
+.L-J.",
+        );
+        assert_eq!(
+            maze.start_candidates(),
+            vec![Pipe::Horizontal, Pipe::NE90, Pipe::NW90]
+        );
+
+        let main_loop = maze.find_loop().unwrap();
+        assert_eq!(main_loop.start_pipe, Pipe::Horizontal);
+        assert_eq!(main_loop.len(), 8);
+
+        assert_eq!(
+            maze.diagnose(&main_loop),
+            Diagnostics {
+                dead_ends: vec![
+                    (ivec2(2, 0), Direction::North),
+                    (ivec2(2, 0), Direction::South)
+                ],
+                off_loop: vec![ivec2(2, 0)],
+            }
+        );
+    }
+
+    #[test]
+    fn loop_vertices() {
+        let maze = Maze::parse(
+            ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+        );
+        let main_loop = maze.find_loop().unwrap();
+
+        assert_eq!(main_loop.len(), 8);
+        assert_eq!(main_loop.start_pipe, Pipe::SE90);
+        assert_eq!(
+            main_loop.vertices(),
+            vec![ivec2(1, 1), ivec2(1, 3), ivec2(3, 3), ivec2(3, 1)]
+        );
+        assert_eq!(main_loop.farthest_distance(), 4);
+        assert_eq!(main_loop.distances()[&ivec2(3, 3)], 4);
+        assert_eq!(main_loop.distances()[&ivec2(3, 1)], 2);
+        assert_eq!(main_loop.twice_area(), 8);
+        assert_eq!(main_loop.enclosed_tiles(), 1);
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use glam::{ivec2, IVec2};
+
+use crate::{Direction, Loop, Maze, Pipe};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Loop(Pipe),
+    Inside(Option<Pipe>),
+    Outside(Option<Pipe>),
+}
+
+impl Pipe {
+    pub fn box_char(&self) -> char {
+        match self {
+            Pipe::Vertical => '│',
+            Pipe::Horizontal => '─',
+            Pipe::NE90 => '└',
+            Pipe::NW90 => '┘',
+            Pipe::SW90 => '┐',
+            Pipe::SE90 => '┌',
+            Pipe::Start => 'S',
+        }
+    }
+}
+
+// Scans every row, flipping between outside and inside each time a loop
+// pipe going north is crossed
+pub fn classify(maze: &Maze, main_loop: &Loop) -> Vec<Vec<Tile>> {
+    let loop_tiles: HashSet<IVec2> = main_loop.tiles.iter().copied().collect();
+
+    maze.tiles
+        .iter()
+        .enumerate()
+        .map(|(y, row)| {
+            let mut inside = false;
+            row.iter()
+                .enumerate()
+                .map(|(x, &pipe)| {
+                    let pos = ivec2(x as i32, y as i32);
+                    if !loop_tiles.contains(&pos) {
+                        return if inside {
+                            Tile::Inside(pipe)
+                        } else {
+                            Tile::Outside(pipe)
+                        };
+                    }
+                    let pipe = match pipe.expect("loop tiles to be pipes") {
+                        Pipe::Start => main_loop.start_pipe,
+                        pipe => pipe,
+                    };
+                    if pipe.connects_to(&Direction::North) {
+                        inside = !inside;
+                    }
+                    Tile::Loop(pipe)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn box_char_or_blank(pipe: Option<Pipe>) -> char {
+    pipe.map_or(' ', |pipe| pipe.box_char())
+}
+
+pub fn to_ansi(maze: &Maze, main_loop: &Loop) -> String {
+    let mut out = String::new();
+    for (y, row) in classify(maze, main_loop).into_iter().enumerate() {
+        for (x, tile) in row.into_iter().enumerate() {
+            let (colour, c) = match tile {
+                Tile::Loop(pipe) if maze.start == ivec2(x as i32, y as i32) => {
+                    ("\x1b[1;31m", pipe.box_char())
+                }
+                Tile::Loop(pipe) => ("\x1b[1;33m", pipe.box_char()),
+                Tile::Inside(pipe) => ("\x1b[42m", box_char_or_blank(pipe)),
+                Tile::Outside(pipe) => ("\x1b[2m", box_char_or_blank(pipe)),
+            };
+            out.push_str(colour);
+            out.push(c);
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// Uncoloured version for writing to a file, with `I` and `O` marking the
+// tiles not on the loop
+pub fn to_text(maze: &Maze, main_loop: &Loop) -> String {
+    let mut out = String::new();
+    for row in classify(maze, main_loop) {
+        out.extend(row.into_iter().map(|tile| match tile {
+            Tile::Loop(pipe) => pipe.box_char(),
+            Tile::Inside(_) => 'I',
+            Tile::Outside(_) => 'O',
+        }));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    #[test]
+    fn text() {
+        let maze = Maze::parse(EXAMPLE);
+        let main_loop = maze.find_loop().unwrap();
+
+        assert_eq!(
+            to_text(&maze, &main_loop),
+            "OOOOOOOOOOO
+O┌───────┐O
+O│┌─────┐│O
+O││OOOOO││O
+O││OOOOO││O
+O│└─┐O┌─┘│O
+O│II│O│II│O
+O└──┘O└──┘O
+OOOOOOOOOOO
+"
+        );
+    }
+
+    #[test]
+    fn inside_matches_enclosed_count() {
+        let maze = Maze::parse(
+            "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L",
+        );
+        let main_loop = maze.find_loop().unwrap();
+        let inside = classify(&maze, &main_loop)
+            .into_iter()
+            .flatten()
+            .filter(|tile| matches!(tile, Tile::Inside(_)))
+            .count();
+
+        assert_eq!(inside, main_loop.enclosed_tiles());
+    }
+}
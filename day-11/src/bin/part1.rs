@@ -1,54 +1,7 @@
-use std::collections::BTreeSet;
-
-use glam::IVec2;
-use itertools::Itertools;
+use day_11::Expansion;
 
 fn process(input: &str) -> String {
-    let galaxies: Vec<IVec2> = input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.char_indices()
-                .filter_map(move |(x, c)| (c == '#').then_some(IVec2::new(x as i32, y as i32)))
-        })
-        .collect();
-
-    let min_x = galaxies.iter().map(|pos| pos.x).min().unwrap();
-    let max_x = galaxies.iter().map(|pos| pos.x).max().unwrap();
-    let min_y = galaxies.iter().map(|pos| pos.y).min().unwrap();
-    let max_y = galaxies.iter().map(|pos| pos.y).max().unwrap();
-    let rows_with_galaxies: BTreeSet<i32> = galaxies.iter().map(|pos| pos.x).collect();
-    let columns_with_galaxies: BTreeSet<i32> = galaxies.iter().map(|pos| pos.y).collect();
-
-    let rows_containing_galaxies = (min_x..max_x).into_iter().collect();
-    let rows_to_expand = rows_with_galaxies
-        .symmetric_difference(&rows_containing_galaxies)
-        .collect_vec();
-    let columns_containing_galaxies = (min_y..max_y).into_iter().collect();
-    let columns_to_expand = columns_with_galaxies
-        .symmetric_difference(&columns_containing_galaxies)
-        .collect_vec();
-
-    let expanded_galaxies = galaxies
-        .into_iter()
-        .map(|pos| {
-            let dx = rows_to_expand.partition_point(|i| i < &&pos.x);
-            let dy = columns_to_expand.partition_point(|i| i < &&pos.y);
-            pos + IVec2::new(dx as i32, dy as i32)
-        })
-        .collect_vec();
-
-    expanded_galaxies
-        .iter()
-        .enumerate()
-        .flat_map(|(step, &galaxie)| {
-            expanded_galaxies.iter().skip(step + 1).map(move |&other| {
-                let diff = (galaxie - other).abs();
-                diff.x + diff.y
-            })
-        })
-        .sum::<i32>()
-        .to_string()
+    day_11::process(input, Expansion::uniform(2)).to_string()
 }
 
 fn main() {
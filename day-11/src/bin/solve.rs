@@ -0,0 +1,50 @@
+use day_11::{closest_pair, distance_sum, expand, farthest_pair, parse, Expansion};
+
+// Usage: solve [input file] [--ratio <n>] [--x-ratio <n>] [--y-ratio <n>] [--render]
+fn main() -> std::io::Result<()> {
+    let mut input_path = None;
+    let mut render = false;
+    let mut expansion = Expansion::uniform(2);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut ratio = || -> u64 {
+            args.next()
+                .and_then(|ratio| ratio.parse().ok())
+                .unwrap_or_else(|| panic!("{arg} to be followed by a ratio"))
+        };
+        match arg.as_str() {
+            "--ratio" => expansion = Expansion::uniform(ratio()),
+            "--x-ratio" => expansion.x = ratio(),
+            "--y-ratio" => expansion.y = ratio(),
+            "--render" => render = true,
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let input = match input_path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+
+    let universe = expand(&parse(&input), expansion);
+    if render {
+        println!("{}", universe.render());
+    }
+
+    let galaxies = universe.galaxies;
+    for (name, pair) in [
+        ("closest", closest_pair(&galaxies)),
+        ("farthest", farthest_pair(&galaxies)),
+    ] {
+        if let Some(pair) = pair {
+            println!(
+                "{name}: galaxies {} and {}, {} apart",
+                pair.a + 1,
+                pair.b + 1,
+                pair.distance
+            );
+        }
+    }
+    println!("Output: {}", distance_sum(&galaxies));
+    Ok(())
+}
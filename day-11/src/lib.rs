@@ -0,0 +1,349 @@
+use std::collections::BTreeSet;
+
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Galaxy {
+    pub x: i128,
+    pub y: i128,
+}
+
+// How many columns (x) and rows (y) every empty column and row turns into.
+// A ratio of 1 leaves the universe as is and 0 removes the empty lines.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Expansion {
+    pub x: u64,
+    pub y: u64,
+}
+
+impl Expansion {
+    pub fn uniform(ratio: u64) -> Self {
+        Expansion { x: ratio, y: ratio }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Universe {
+    pub width: i128,
+    pub height: i128,
+    pub galaxies: Vec<Galaxy>,
+}
+
+impl Universe {
+    pub fn render(&self) -> String {
+        let galaxies: BTreeSet<(i128, i128)> = self
+            .galaxies
+            .iter()
+            .map(|galaxy| (galaxy.y, galaxy.x))
+            .collect();
+        (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| if galaxies.contains(&(y, x)) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+pub fn parse(input: &str) -> Universe {
+    let galaxies = input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.char_indices().filter_map(move |(x, c)| {
+                (c == '#').then_some(Galaxy {
+                    x: x as i128,
+                    y: y as i128,
+                })
+            })
+        })
+        .collect();
+
+    Universe {
+        width: input.lines().map(|line| line.len()).max().unwrap_or(0) as i128,
+        height: input.lines().count() as i128,
+        galaxies,
+    }
+}
+
+// Every line of the grid along an axis that holds no galaxy
+fn empty_lines(size: i128, occupied: impl Iterator<Item = i128>) -> Vec<i128> {
+    let occupied: BTreeSet<i128> = occupied.collect();
+    (0..size)
+        .filter(|line| !occupied.contains(line))
+        .collect_vec()
+}
+
+pub fn expand(universe: &Universe, expansion: Expansion) -> Universe {
+    let empty_columns = empty_lines(
+        universe.width,
+        universe.galaxies.iter().map(|galaxy| galaxy.x),
+    );
+    let empty_rows = empty_lines(
+        universe.height,
+        universe.galaxies.iter().map(|galaxy| galaxy.y),
+    );
+
+    let growth_x = expansion.x as i128 - 1;
+    let growth_y = expansion.y as i128 - 1;
+    let galaxies = universe
+        .galaxies
+        .iter()
+        .map(|galaxy| {
+            let dx = empty_columns.partition_point(|column| column < &galaxy.x) as i128;
+            let dy = empty_rows.partition_point(|row| row < &galaxy.y) as i128;
+            Galaxy {
+                x: galaxy.x + dx * growth_x,
+                y: galaxy.y + dy * growth_y,
+            }
+        })
+        .collect();
+
+    Universe {
+        width: universe.width + empty_columns.len() as i128 * growth_x,
+        height: universe.height + empty_rows.len() as i128 * growth_y,
+        galaxies,
+    }
+}
+
+pub fn distance(a: &Galaxy, b: &Galaxy) -> i128 {
+    (a.x - b.x).abs() + (a.y - b.y).abs()
+}
+
+// Sum of the distances between every pair of values, after sorting each
+// value is `i` times further than the ones before it minus their sum
+fn axis_distance_sum(mut values: Vec<i128>) -> i128 {
+    values.sort_unstable();
+    let mut preceding = 0;
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let sum = value * i as i128 - preceding;
+            preceding += value;
+            sum
+        })
+        .sum()
+}
+
+pub fn distance_sum(galaxies: &[Galaxy]) -> i128 {
+    axis_distance_sum(galaxies.iter().map(|galaxy| galaxy.x).collect())
+        + axis_distance_sum(galaxies.iter().map(|galaxy| galaxy.y).collect())
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Pair {
+    // Indices into the galaxies the pair was found in
+    pub a: usize,
+    pub b: usize,
+    pub distance: i128,
+}
+
+impl Pair {
+    pub fn new(galaxies: &[Galaxy], a: usize, b: usize) -> Self {
+        Pair {
+            a: a.min(b),
+            b: a.max(b),
+            distance: distance(&galaxies[a], &galaxies[b]),
+        }
+    }
+}
+
+// Sweeps the galaxies by x, only comparing against the ones within the best
+// distance so far on both axes
+pub fn closest_pair(galaxies: &[Galaxy]) -> Option<Pair> {
+    let by_x = (0..galaxies.len())
+        .sorted_by_key(|&i| (galaxies[i].x, galaxies[i].y))
+        .collect_vec();
+
+    let mut best: Option<Pair> = None;
+    let mut window: BTreeSet<(i128, usize)> = BTreeSet::new();
+    let mut left = 0;
+    for &i in &by_x {
+        let galaxy = galaxies[i];
+        if let Some(best) = best {
+            while galaxy.x - galaxies[by_x[left]].x > best.distance {
+                window.remove(&(galaxies[by_x[left]].y, by_x[left]));
+                left += 1;
+            }
+        }
+
+        let reach = best.map_or(i128::MAX, |best| best.distance);
+        let candidates = window.range(
+            (galaxy.y.saturating_sub(reach), 0)..=(galaxy.y.saturating_add(reach), usize::MAX),
+        );
+        for &(_, j) in candidates {
+            let pair = Pair::new(galaxies, i, j);
+            if best.is_none_or(|best| pair.distance < best.distance) {
+                best = Some(pair);
+            }
+        }
+        window.insert((galaxy.y, i));
+    }
+    best
+}
+
+// The farthest pair maximises either x + y or x - y, so only the extremes of
+// those need comparing
+pub fn farthest_pair(galaxies: &[Galaxy]) -> Option<Pair> {
+    if galaxies.len() < 2 {
+        return None;
+    }
+    let keys: [fn(&Galaxy) -> i128; 2] = [|g| g.x + g.y, |g| g.x - g.y];
+    keys.into_iter()
+        .filter_map(|key| {
+            let (min, max) = (0..galaxies.len())
+                .minmax_by_key(|&i| key(&galaxies[i]))
+                .into_option()?;
+            Some(Pair::new(galaxies, min, max))
+        })
+        .max_by_key(|pair| pair.distance)
+}
+
+pub fn process(input: &str, expansion: Expansion) -> i128 {
+    distance_sum(&expand(&parse(input), expansion).galaxies)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn ratio_one_is_unexpanded() {
+        let universe = parse(EXAMPLE);
+        assert_eq!(expand(&universe, Expansion::uniform(1)), universe);
+    }
+
+    #[test]
+    fn ratio_zero_removes_empty_lines() {
+        let expanded = expand(&parse(EXAMPLE), Expansion::uniform(0)).galaxies;
+        assert_eq!(expanded[0], Galaxy { x: 2, y: 0 });
+        assert_eq!(expanded[7], Galaxy { x: 0, y: 7 });
+        assert_eq!(process(EXAMPLE, Expansion::uniform(0)), 210);
+    }
+
+    #[test]
+    fn per_axis_ratio() {
+        let expanded = expand(&parse(EXAMPLE), Expansion { x: 10, y: 1 }).galaxies;
+        assert_eq!(expanded[1], Galaxy { x: 25, y: 1 });
+        assert_eq!(
+            process(EXAMPLE, Expansion { x: 2, y: 1 }) + process(EXAMPLE, Expansion { x: 1, y: 2 })
+                - process(EXAMPLE, Expansion::uniform(1)),
+            374
+        );
+    }
+
+    fn brute_force_pairs(galaxies: &[Galaxy]) -> Vec<i128> {
+        galaxies
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| distance(a, b))
+            .collect()
+    }
+
+    #[test]
+    fn distance_sum_matches_brute_force() {
+        for ratio in [0, 1, 2, 10] {
+            let galaxies = expand(&parse(EXAMPLE), Expansion::uniform(ratio)).galaxies;
+            assert_eq!(
+                distance_sum(&galaxies),
+                brute_force_pairs(&galaxies).into_iter().sum::<i128>()
+            );
+        }
+    }
+
+    #[test]
+    fn closest_and_farthest() {
+        let galaxies = expand(&parse(EXAMPLE), Expansion::uniform(2)).galaxies;
+        let pairs = brute_force_pairs(&galaxies);
+
+        let closest = closest_pair(&galaxies).unwrap();
+        assert_eq!(closest.distance, *pairs.iter().min().unwrap());
+        assert_eq!(
+            closest.distance,
+            distance(&galaxies[closest.a], &galaxies[closest.b])
+        );
+
+        let farthest = farthest_pair(&galaxies).unwrap();
+        assert_eq!(farthest.distance, *pairs.iter().max().unwrap());
+        assert_eq!(
+            farthest.distance,
+            distance(&galaxies[farthest.a], &galaxies[farthest.b])
+        );
+
+        assert_eq!(closest_pair(&galaxies[..1]), None);
+        assert_eq!(farthest_pair(&galaxies[..1]), None);
+    }
+
+    #[test]
+    fn render_expanded() {
+        assert_eq!(
+            expand(&parse(EXAMPLE), Expansion::uniform(2)).render(),
+            "....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#......."
+        );
+    }
+
+    #[test]
+    fn empty_border_lines() {
+        let universe = parse(
+            "......
+..#...
+......
+.#....
+......",
+        );
+        assert_eq!((universe.width, universe.height), (6, 5));
+
+        let expanded = expand(&universe, Expansion::uniform(2));
+        assert_eq!((expanded.width, expanded.height), (10, 8));
+        assert_eq!(
+            expanded.galaxies,
+            vec![Galaxy { x: 3, y: 2 }, Galaxy { x: 2, y: 5 }]
+        );
+        assert_eq!(
+            expanded.render(),
+            "..........
+..........
+...#......
+..........
+..........
+..#.......
+..........
+.........."
+        );
+
+        let removed = expand(&universe, Expansion::uniform(0));
+        assert_eq!(removed.render(), ".#\n#.");
+    }
+
+    #[test]
+    fn huge_ratio() {
+        assert_eq!(
+            process(EXAMPLE, Expansion::uniform(u64::MAX)),
+            82 * (u64::MAX as i128 - 1) + 292
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use day_12::{arrangements, parse, unfold};
+
+// Usage: arrangements "<records> <groups>" [--unfold <factor>]
+fn main() {
+    let mut row = None;
+    let mut factor = 1;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--unfold" => {
+                factor = args
+                    .next()
+                    .and_then(|factor| factor.parse().ok())
+                    .expect("--unfold to be followed by a factor")
+            }
+            _ => row = Some(arg),
+        }
+    }
+
+    let row = row.expect("a row such as \"???.### 1,1,3\"");
+    let (_, springs) = parse(&row).expect("valid row");
+    let (records, groups) = &springs[0];
+    let (records, groups) = unfold(records, groups, factor);
+
+    let mut count = 0;
+    for arrangement in arrangements(&records, &groups) {
+        println!("{arrangement}");
+        count += 1;
+    }
+    println!("Output: {count}");
+}
@@ -0,0 +1,36 @@
+use day_12::{check::check, parse};
+
+// Usage: check [input file]
+fn main() -> std::io::Result<()> {
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+    let (_, springs) = parse(&input).expect("valid springs");
+
+    let mut impossible = 0;
+    for (line, (records, groups)) in springs.iter().enumerate() {
+        let Some(report) = check(records, groups) else {
+            continue;
+        };
+        impossible += 1;
+
+        println!("line {}:", line + 1);
+        for problem in report.problems {
+            println!("  {problem}");
+        }
+        match report.repairs {
+            Some(repairs) => {
+                for repair in repairs {
+                    println!(
+                        "  change {} from {:?} to {:?}",
+                        repair.index, repair.from, repair.to
+                    );
+                }
+            }
+            None => println!("  no change to the springs can fix this row"),
+        }
+    }
+    println!("{impossible} of {} rows are impossible", springs.len());
+    Ok(())
+}
@@ -0,0 +1,330 @@
+use std::fmt;
+
+use crate::{count_arrangements, Status};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Problem {
+    // The groups and the operational springs between them don't fit at all
+    NotEnoughRoom {
+        needed: usize,
+        available: usize,
+    },
+    TooManyDamaged {
+        damaged: usize,
+        expected: usize,
+    },
+    // No stretch of possibly damaged springs is long enough for the group
+    GroupTooLong {
+        group: u32,
+        longest: usize,
+    },
+    DamagedRunTooLong {
+        start: usize,
+        len: usize,
+        longest: u32,
+    },
+    // Nothing obvious, the fixed springs just can't line up with the groups
+    ConflictingSprings,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::NotEnoughRoom { needed, available } => write!(
+                f,
+                "groups need {needed} springs but the row only has {available}"
+            ),
+            Problem::TooManyDamaged { damaged, expected } => write!(
+                f,
+                "{damaged} springs are damaged but the groups only cover {expected}"
+            ),
+            Problem::GroupTooLong { group, longest } => write!(
+                f,
+                "group of {group} is longer than any stretch of {longest} possibly damaged springs"
+            ),
+            Problem::DamagedRunTooLong {
+                start,
+                len,
+                longest,
+            } => write!(
+                f,
+                "{len} damaged springs from {start} are more than the largest group of {longest}"
+            ),
+            Problem::ConflictingSprings => {
+                write!(
+                    f,
+                    "known springs conflict with every placement of the groups"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Repair {
+    pub index: usize,
+    pub from: Status,
+    pub to: Status,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Report {
+    pub problems: Vec<Problem>,
+    // Fewest changes to known springs that allow an arrangement, None if no
+    // change to the springs alone can fix the row
+    pub repairs: Option<Vec<Repair>>,
+}
+
+// Lengths and starts of the stretches of springs matching `keep`
+fn runs(records: &[Status], keep: impl Fn(&Status) -> bool) -> Vec<(usize, usize)> {
+    let mut runs = vec![];
+    let mut start = None;
+    for (i, status) in records.iter().enumerate() {
+        match (keep(status), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                runs.push((s, i - s));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        runs.push((s, records.len() - s));
+    }
+    runs
+}
+
+fn problems(records: &[Status], groups: &[u32]) -> Vec<Problem> {
+    let mut problems = vec![];
+
+    let expected: usize = groups.iter().map(|group| *group as usize).sum();
+    let needed = expected + groups.len().saturating_sub(1);
+    if needed > records.len() {
+        problems.push(Problem::NotEnoughRoom {
+            needed,
+            available: records.len(),
+        });
+    }
+
+    let damaged = records
+        .iter()
+        .filter(|status| **status == Status::Damaged)
+        .count();
+    if damaged > expected {
+        problems.push(Problem::TooManyDamaged { damaged, expected });
+    }
+
+    let longest = runs(records, |status| *status != Status::Operational)
+        .into_iter()
+        .map(|(_, len)| len)
+        .max()
+        .unwrap_or(0);
+    if let Some(&group) = groups
+        .iter()
+        .filter(|group| **group as usize > longest)
+        .max()
+    {
+        problems.push(Problem::GroupTooLong { group, longest });
+    }
+
+    let longest = groups.iter().copied().max().unwrap_or(0);
+    problems.extend(
+        runs(records, |status| *status == Status::Damaged)
+            .into_iter()
+            .filter(|(_, len)| *len > longest as usize)
+            .map(|(start, len)| Problem::DamagedRunTooLong {
+                start,
+                len,
+                longest,
+            }),
+    );
+
+    if problems.is_empty() {
+        problems.push(Problem::ConflictingSprings);
+    }
+    problems
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Operational,
+    Group(usize),
+}
+
+// Like the counting table, but `cost[i][g]` is the fewest known springs in
+// `records[i..]` that disagree with some placement of `groups[g..]`
+fn repairs(records: &[Status], groups: &[u32]) -> Option<Vec<Repair>> {
+    let len = records.len();
+    let disagrees = |i: usize, status: Status| {
+        records
+            .get(i)
+            .is_some_and(|known| *known != Status::Unknown && *known != status) as usize
+    };
+
+    let mut cost = vec![vec![None; groups.len() + 1]; len + 2];
+    let mut steps = vec![vec![Step::Operational; groups.len() + 1]; len + 2];
+    cost[len][groups.len()] = Some(0);
+    cost[len + 1][groups.len()] = Some(0);
+
+    for i in (0..len).rev() {
+        for g in 0..=groups.len() {
+            let mut best: Option<(usize, Step)> = cost[i + 1][g]
+                .map(|rest| (rest + disagrees(i, Status::Operational), Step::Operational));
+
+            if g < groups.len() {
+                let end = i + groups[g] as usize;
+                if end <= len {
+                    if let Some(rest) = cost[(end + 1).min(len + 1)][g + 1] {
+                        let group_cost = (i..end)
+                            .map(|j| disagrees(j, Status::Damaged))
+                            .sum::<usize>()
+                            + disagrees(end, Status::Operational);
+                        let total = rest + group_cost;
+                        if best.is_none_or(|(best, _)| total < best) {
+                            best = Some((total, Step::Group(end)));
+                        }
+                    }
+                }
+            }
+
+            if let Some((total, step)) = best {
+                cost[i][g] = Some(total);
+                steps[i][g] = step;
+            }
+        }
+    }
+
+    cost[0][0]?;
+
+    let mut repairs = vec![];
+    let mut repair = |index: usize, to: Status| {
+        if disagrees(index, to) == 1 {
+            repairs.push(Repair {
+                index,
+                from: records[index],
+                to,
+            });
+        }
+    };
+    let (mut i, mut g) = (0, 0);
+    while i < len {
+        match steps[i][g] {
+            Step::Operational => {
+                repair(i, Status::Operational);
+                i += 1;
+            }
+            Step::Group(end) => {
+                (i..end).for_each(|j| repair(j, Status::Damaged));
+                repair(end, Status::Operational);
+                i = end + 1;
+                g += 1;
+            }
+        }
+    }
+    Some(repairs)
+}
+
+// None when the row has at least one arrangement
+pub fn check(records: &[Status], groups: &[u32]) -> Option<Report> {
+    if count_arrangements(records, groups) != Some(0) {
+        return None;
+    }
+    Some(Report {
+        problems: problems(records, groups),
+        repairs: repairs(records, groups),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+
+    fn check_row(line: &str) -> Option<Report> {
+        let (_, springs) = parse(line).unwrap();
+        let (records, groups) = &springs[0];
+        check(records, groups)
+    }
+
+    #[test]
+    fn consistent_row() {
+        assert_eq!(check_row("???.### 1,1,3"), None);
+    }
+
+    #[test]
+    fn too_many_damaged() {
+        assert_eq!(
+            check_row("###.# 3"),
+            Some(Report {
+                problems: vec![Problem::TooManyDamaged {
+                    damaged: 4,
+                    expected: 3
+                }],
+                repairs: Some(vec![Repair {
+                    index: 4,
+                    from: Status::Damaged,
+                    to: Status::Operational
+                }]),
+            })
+        );
+    }
+
+    #[test]
+    fn not_enough_room() {
+        let report = check_row("??? 2,1").unwrap();
+        assert_eq!(
+            report.problems,
+            vec![Problem::NotEnoughRoom {
+                needed: 4,
+                available: 3
+            }]
+        );
+        assert_eq!(report.repairs, None);
+    }
+
+    fn assert_repairs_fix(line: &str, changes: usize) {
+        let (_, springs) = parse(line).unwrap();
+        let (mut records, groups) = springs[0].clone();
+        let repairs = check(&records, &groups).unwrap().repairs.unwrap();
+        assert_eq!(repairs.len(), changes);
+
+        for repair in repairs {
+            assert_eq!(records[repair.index], repair.from);
+            records[repair.index] = repair.to;
+        }
+        assert_eq!(check(&records, &groups), None);
+    }
+
+    #[test]
+    fn group_and_run_too_long() {
+        assert_eq!(
+            check_row("####.? 3,1").unwrap().problems,
+            vec![Problem::DamagedRunTooLong {
+                start: 0,
+                len: 4,
+                longest: 3
+            }]
+        );
+        assert_repairs_fix("####.? 3,1", 1);
+        assert_repairs_fix("??.#### 3,1", 2);
+
+        assert_eq!(
+            check_row("??.?? 3").unwrap().problems,
+            vec![Problem::GroupTooLong {
+                group: 3,
+                longest: 2
+            }]
+        );
+        assert_repairs_fix("??.?? 3", 1);
+    }
+
+    #[test]
+    fn conflicting_springs() {
+        assert_eq!(
+            check_row("#???# 3").unwrap().problems,
+            vec![Problem::ConflictingSprings]
+        );
+        assert_repairs_fix("#???# 3", 1);
+    }
+}
@@ -0,0 +1,322 @@
+use nom::{
+    character::complete::{self, multispace1, one_of},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult, Parser,
+};
+
+pub mod check;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Status {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Status {
+    type Error = ();
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '.' => Status::Operational,
+            '#' => Status::Damaged,
+            '?' => Status::Unknown,
+            _ => return Err(()),
+        })
+    }
+}
+
+// Records of a row and the sizes of its contiguous damaged groups
+pub type Row = (Vec<Status>, Vec<u32>);
+
+// Repeats the records `factor` times with an unknown spring between each
+// copy, and the groups `factor` times
+pub fn unfold(records: &[Status], groups: &[u32], factor: usize) -> Row {
+    let records = vec![records; factor].join(&Status::Unknown);
+    let groups = groups.repeat(factor);
+    (records, groups)
+}
+
+// Ways to place `groups` in `records`, counted from the back so that
+// `ways[i][g]` is the number of arrangements of `groups[g..]` in `records[i..]`,
+// or None if that doesn't fit in a u128. Counts only ever add up, so an
+// overflowed state only matters if the count for the whole row overflows too.
+struct Table<'a> {
+    records: &'a [Status],
+    groups: &'a [u32],
+    // Operational springs seen before each position, to check a group fits
+    // in a single lookup
+    operational_before: Vec<usize>,
+    ways: Vec<Vec<Option<u128>>>,
+}
+
+impl<'a> Table<'a> {
+    fn new(records: &'a [Status], groups: &'a [u32]) -> Self {
+        let len = records.len();
+
+        let mut operational_before = vec![0; len + 1];
+        for (i, status) in records.iter().enumerate() {
+            operational_before[i + 1] =
+                operational_before[i] + (*status == Status::Operational) as usize;
+        }
+
+        let mut table = Table {
+            records,
+            groups,
+            operational_before,
+            ways: vec![vec![Some(0); groups.len() + 1]; len + 2],
+        };
+        table.ways[len][groups.len()] = Some(1);
+        table.ways[len + 1][groups.len()] = Some(1);
+
+        for i in (0..len).rev() {
+            for g in 0..=groups.len() {
+                let mut count = Some(0);
+                if records[i] != Status::Damaged {
+                    count = table.ways[i + 1][g];
+                }
+                if let Some(end) = table.group_end(i, g) {
+                    // Skip the operational spring that has to follow the group
+                    count = count
+                        .zip(table.ways[end + 1][g + 1])
+                        .and_then(|(count, ways)| count.checked_add(ways));
+                }
+                table.ways[i][g] = count;
+            }
+        }
+        table
+    }
+
+    fn has_ways(&self, i: usize, g: usize) -> bool {
+        self.ways[i][g] != Some(0)
+    }
+
+    // Where group `g` ends if it can start at position `i`
+    fn group_end(&self, i: usize, g: usize) -> Option<usize> {
+        if self.records[i] == Status::Operational || g >= self.groups.len() {
+            return None;
+        }
+        let end = i + self.groups[g] as usize;
+        let fits = end <= self.records.len()
+            && self.operational_before[end] == self.operational_before[i]
+            && self.records.get(end) != Some(&Status::Damaged);
+        fits.then_some(end)
+    }
+}
+
+// None if there are more arrangements than fit in a u128
+pub fn count_arrangements(records: &[Status], groups: &[u32]) -> Option<u128> {
+    Table::new(records, groups).ways[0][0]
+}
+
+// Lazily yields every concrete arrangement, such as `#.#.###`, only following
+// the branches the counting table knows lead somewhere
+pub struct Arrangements<'a> {
+    table: Table<'a>,
+    stack: Vec<(usize, usize, String)>,
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.table.records.len();
+        while let Some((i, g, arrangement)) = self.stack.pop() {
+            if i >= len {
+                return Some(arrangement);
+            }
+
+            if let Some(end) = self.table.group_end(i, g) {
+                if self.table.has_ways(end + 1, g + 1) {
+                    let mut arrangement = arrangement.clone();
+                    arrangement.extend(std::iter::repeat_n('#', end - i));
+                    if end < len {
+                        arrangement.push('.');
+                    }
+                    self.stack.push((end + 1, g + 1, arrangement));
+                }
+            }
+            if self.table.records[i] != Status::Damaged && self.table.has_ways(i + 1, g) {
+                self.stack.push((i + 1, g, arrangement + "."));
+            }
+        }
+        None
+    }
+}
+
+pub fn arrangements<'a>(records: &'a [Status], groups: &'a [u32]) -> Arrangements<'a> {
+    let table = Table::new(records, groups);
+    let stack = if table.has_ways(0, 0) {
+        vec![(0, 0, String::new())]
+    } else {
+        vec![]
+    };
+    Arrangements { table, stack }
+}
+
+pub fn parse(input: &str) -> IResult<&str, Vec<Row>> {
+    separated_list1(
+        multispace1,
+        separated_pair(
+            many1(one_of(".#?").map(|c| c.try_into().unwrap())),
+            multispace1,
+            separated_list1(complete::char(','), complete::u32),
+        ),
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn count(line: &str) -> u128 {
+        let (_, springs) = parse(line).unwrap();
+        let (records, groups) = &springs[0];
+        count_arrangements(records, groups).unwrap()
+    }
+
+    #[test]
+    fn example_rows() {
+        assert_eq!(count("???.### 1,1,3"), 1);
+        assert_eq!(count(".??..??...?##. 1,1,3"), 4);
+        assert_eq!(count("?#?#?#?#?#?#?#? 1,3,1,6"), 1);
+        assert_eq!(count("????.#...#... 4,1,1"), 1);
+        assert_eq!(count("????.######..#####. 1,6,5"), 4);
+        assert_eq!(count("?###???????? 3,2,1"), 10);
+    }
+
+    #[test]
+    fn impossible_rows() {
+        assert_eq!(count("### 1"), 0);
+        assert_eq!(count("??. 3"), 0);
+        assert_eq!(count("#.# 1"), 0);
+    }
+
+    // Tries every assignment of the unknown springs
+    fn brute_force(records: &[Status], groups: &[u32]) -> Vec<String> {
+        let unknown: Vec<usize> = (0..records.len())
+            .filter(|&i| records[i] == Status::Unknown)
+            .collect();
+        (0..1u32 << unknown.len())
+            .map(|bits| {
+                records
+                    .iter()
+                    .enumerate()
+                    .map(|(i, status)| match status {
+                        Status::Operational => '.',
+                        Status::Damaged => '#',
+                        Status::Unknown => {
+                            let bit = unknown.iter().position(|&u| u == i).unwrap();
+                            if bits & 1 << bit != 0 {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .filter(|arrangement| {
+                let found: Vec<u32> = arrangement
+                    .split('.')
+                    .filter(|group| !group.is_empty())
+                    .map(|group| group.len() as u32)
+                    .collect();
+                found == groups
+            })
+            .collect()
+    }
+
+    #[test]
+    fn arrangements_match_brute_force() {
+        let (_, springs) = parse(
+            "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+#.#?.?#? 1,1,2",
+        )
+        .unwrap();
+
+        for (records, groups) in springs {
+            for factor in [1, 2] {
+                let (records, groups) = unfold(&records, &groups, factor);
+                if records.iter().filter(|s| **s == Status::Unknown).count() > 16 {
+                    continue;
+                }
+                let mut expected = brute_force(&records, &groups);
+                let mut found: Vec<String> = arrangements(&records, &groups).collect();
+                expected.sort();
+                found.sort();
+
+                assert_eq!(found, expected);
+                assert_eq!(
+                    count_arrangements(&records, &groups),
+                    Some(expected.len() as u128)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn arrangements_of_row() {
+        let (_, springs) = parse("???.### 1,1,3").unwrap();
+        let (records, groups) = &springs[0];
+        assert_eq!(
+            arrangements(records, groups).collect::<Vec<_>>(),
+            vec!["#.#.###"]
+        );
+        assert_eq!(arrangements(records, &[4]).next(), None);
+    }
+
+    #[test]
+    fn unfold_factor() {
+        let (_, springs) = parse(".# 1").unwrap();
+        let (records, groups) = &springs[0];
+        let (records, groups) = unfold(records, groups, 3);
+        assert_eq!(
+            records,
+            vec![
+                Status::Operational,
+                Status::Damaged,
+                Status::Unknown,
+                Status::Operational,
+                Status::Damaged,
+                Status::Unknown,
+                Status::Operational,
+                Status::Damaged
+            ]
+        );
+        assert_eq!(groups, vec![1, 1, 1]);
+        let (_, springs) = parse("?###???????? 3,2,1").unwrap();
+        let (records, groups) = &springs[0];
+        let (records, groups) = unfold(records, groups, 5);
+        assert_eq!(count_arrangements(&records, &groups), Some(506250));
+    }
+
+    #[test]
+    fn long_record() {
+        let records = vec![Status::Unknown; 300];
+        assert_eq!(count_arrangements(&records, &[1; 150]), Some(151));
+        assert_eq!(count_arrangements(&records, &[1]), Some(300));
+        assert_eq!(count_arrangements(&records, &[1, 1]), Some(299 * 298 / 2));
+    }
+
+    #[test]
+    fn huge_counts() {
+        // 30 groups of 1 in 200 cells is 171 choose 30, past what a u64 holds
+        let records = vec![Status::Unknown; 200];
+        assert_eq!(
+            count_arrangements(&records, &[1; 30]),
+            Some(2464841110946564122718435250985224)
+        );
+
+        // 451 choose 150 doesn't fit in a u128 either
+        let records = vec![Status::Unknown; 600];
+        assert_eq!(count_arrangements(&records, &[1; 150]), None);
+        assert!(arrangements(&records, &[1; 150]).next().is_some());
+    }
+}
@@ -0,0 +1,56 @@
+use day_13::{parse, render, Axis};
+
+// Usage: report [input file] [--smudges <n>] [--plain]
+fn main() -> std::io::Result<()> {
+    let mut input_path = None;
+    let mut smudges = 1;
+    let mut plain = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--smudges" => {
+                smudges = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .expect("--smudges to be followed by a count")
+            }
+            "--plain" => plain = true,
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let input = match input_path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+    let (_, patterns) = parse(&input).expect("valid patterns");
+
+    let mut total = 0;
+    for (i, pattern) in patterns.iter().enumerate() {
+        let reflections = pattern.report(smudges);
+        if reflections.is_empty() {
+            println!("pattern {}: no mirror with {smudges} smudges\n", i + 1);
+            continue;
+        }
+        for reflection in reflections {
+            let axis = match reflection.axis {
+                Axis::Row(before) => format!("between rows {before} and {}", before + 1),
+                Axis::Column(before) => format!("between columns {before} and {}", before + 1),
+            };
+            println!("pattern {}: mirror {axis}", i + 1);
+            for smudge in &reflection.smudges {
+                println!(
+                    "  smudge at {:?}, mirrored at {:?}",
+                    smudge.cell, smudge.mirrored
+                );
+            }
+            match plain {
+                true => println!("{}", render::to_text(pattern, &reflection)),
+                false => println!("{}", render::to_ansi(pattern, &reflection)),
+            }
+            total += reflection.axis.summary();
+        }
+    }
+    println!("Output: {total}");
+    Ok(())
+}
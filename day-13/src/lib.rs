@@ -0,0 +1,391 @@
+use nom::{
+    character::complete::{line_ending, multispace1, one_of},
+    error::{Error, ErrorKind},
+    multi::{many1, separated_list1},
+    IResult, Parser,
+};
+
+pub mod render;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Ash,
+    Rock,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '.' => Tile::Ash,
+            '#' => Tile::Rock,
+            _ => return Err(()),
+        })
+    }
+}
+
+// A mirror line, holding how many rows are above it or columns left of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Row(usize),
+    Column(usize),
+}
+
+impl Axis {
+    pub fn summary(&self) -> usize {
+        match self {
+            Axis::Row(rows) => 100 * rows,
+            Axis::Column(columns) => *columns,
+        }
+    }
+}
+
+// A cell that differs from its reflection, as (x, y). Flipping either of
+// them fixes the mirror, `cell` is the one before the mirror line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Smudge {
+    pub cell: (usize, usize),
+    pub mirrored: (usize, usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reflection {
+    pub axis: Axis,
+    pub smudges: Vec<Smudge>,
+}
+
+// A row or column with a bit set for each rock, 64 cells to a word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask(pub Vec<u64>);
+
+impl Mask {
+    fn new(len: usize) -> Self {
+        Mask(vec![0; len.div_ceil(64)])
+    }
+
+    fn set(&mut self, i: usize) {
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        self.0[i / 64] & 1 << (i % 64) != 0
+    }
+
+    // How many cells differ from `other`
+    fn mismatches(&self, other: &Mask) -> u32 {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+
+    // The cells that differ from `other`
+    fn differences<'a>(&'a self, other: &'a Mask) -> impl Iterator<Item = usize> + 'a {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .enumerate()
+            .flat_map(|(word, (a, b))| {
+                let mut diff = a ^ b;
+                std::iter::from_fn(move || {
+                    let bit = diff.trailing_zeros() as usize;
+                    diff &= diff.checked_sub(1)?;
+                    Some(word * 64 + bit)
+                })
+            })
+    }
+}
+
+// Every row and column packed into a mask, so comparing two lines is an xor
+// for every 64 cells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pub width: usize,
+    pub height: usize,
+    pub rows: Vec<Mask>,
+    pub columns: Vec<Mask>,
+}
+
+impl Pattern {
+    // None if the rows aren't all the same length
+    pub fn new(tiles: &[Vec<Tile>]) -> Option<Self> {
+        let height = tiles.len();
+        let width = tiles.first().map_or(0, |row| row.len());
+
+        let mut rows = vec![Mask::new(width); height];
+        let mut columns = vec![Mask::new(height); width];
+        for (y, row) in tiles.iter().enumerate() {
+            if row.len() != width {
+                return None;
+            }
+            for (x, tile) in row.iter().enumerate() {
+                if *tile == Tile::Rock {
+                    rows[y].set(x);
+                    columns[x].set(y);
+                }
+            }
+        }
+
+        Some(Pattern {
+            width,
+            height,
+            rows,
+            columns,
+        })
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Tile {
+        if self.rows[y].get(x) {
+            Tile::Rock
+        } else {
+            Tile::Ash
+        }
+    }
+
+    // Every axis where exactly `smudges` cells differ from their reflection,
+    // rows first
+    pub fn reflections(&self, smudges: u32) -> Vec<Axis> {
+        let rows = mirrors(&self.rows, smudges).map(Axis::Row);
+        let columns = mirrors(&self.columns, smudges).map(Axis::Column);
+        rows.chain(columns).collect()
+    }
+
+    // Cells that differ from their reflection across `axis`
+    pub fn smudges(&self, axis: Axis) -> Vec<Smudge> {
+        let (lines, before) = match axis {
+            Axis::Row(before) => (&self.rows, before),
+            Axis::Column(before) => (&self.columns, before),
+        };
+        let mut smudges = vec![];
+        for (a, b) in (0..before).rev().zip(before..lines.len()) {
+            for along in lines[a].differences(&lines[b]) {
+                smudges.push(match axis {
+                    Axis::Row(_) => Smudge {
+                        cell: (along, a),
+                        mirrored: (along, b),
+                    },
+                    Axis::Column(_) => Smudge {
+                        cell: (a, along),
+                        mirrored: (b, along),
+                    },
+                });
+            }
+        }
+        smudges
+    }
+
+    pub fn report(&self, smudges: u32) -> Vec<Reflection> {
+        self.reflections(smudges)
+            .into_iter()
+            .map(|axis| Reflection {
+                axis,
+                smudges: self.smudges(axis),
+            })
+            .collect()
+    }
+}
+
+// Cells that differ when folding `lines` between `before - 1` and `before`
+fn mismatches(lines: &[Mask], before: usize) -> u32 {
+    lines[..before]
+        .iter()
+        .rev()
+        .zip(&lines[before..])
+        .map(|(a, b)| a.mismatches(b))
+        .sum()
+}
+
+fn mirrors(lines: &[Mask], smudges: u32) -> impl Iterator<Item = usize> + '_ {
+    (1..lines.len()).filter(move |&before| mismatches(lines, before) == smudges)
+}
+
+// Sum of the summaries of every axis found with `smudges` in each pattern
+pub fn summarize(patterns: &[Pattern], smudges: u32) -> usize {
+    patterns
+        .iter()
+        .flat_map(|pattern| pattern.reflections(smudges))
+        .map(|axis| axis.summary())
+        .sum()
+}
+
+// Fails outright on a pattern with rows of different lengths
+fn pattern(input: &str) -> IResult<&str, Pattern> {
+    let (rest, tiles) = separated_list1(
+        line_ending,
+        many1(one_of(".#").map(|c| -> Tile { c.try_into().unwrap() })),
+    )
+    .parse(input)?;
+    match Pattern::new(&tiles) {
+        Some(pattern) => Ok((rest, pattern)),
+        None => Err(nom::Err::Failure(Error::new(input, ErrorKind::Verify))),
+    }
+}
+
+pub fn parse(input: &str) -> IResult<&str, Vec<Pattern>> {
+    separated_list1(multispace1, pattern).parse(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn bitmasks() {
+        let (_, patterns) = parse(EXAMPLE).unwrap();
+        let pattern = &patterns[0];
+        assert_eq!((pattern.width, pattern.height), (9, 7));
+        assert_eq!(pattern.rows[0], Mask(vec![0b011001101]));
+        assert_eq!(pattern.columns[0], Mask(vec![0b1001101]));
+        assert_eq!(pattern.get(0, 0), Tile::Rock);
+        assert_eq!(pattern.get(1, 0), Tile::Ash);
+    }
+
+    #[test]
+    fn smudge_counts() {
+        let (_, patterns) = parse(EXAMPLE).unwrap();
+        assert_eq!(patterns[0].reflections(0), vec![Axis::Column(5)]);
+        assert_eq!(patterns[1].reflections(0), vec![Axis::Row(4)]);
+        assert_eq!(patterns[0].reflections(1), vec![Axis::Row(3)]);
+        assert_eq!(patterns[1].reflections(1), vec![Axis::Row(1)]);
+        assert_eq!(summarize(&patterns, 0), 405);
+        assert_eq!(summarize(&patterns, 1), 400);
+    }
+
+    #[test]
+    fn smudge_cells() {
+        let (_, patterns) = parse(EXAMPLE).unwrap();
+        assert_eq!(
+            patterns[0].report(1),
+            vec![Reflection {
+                axis: Axis::Row(3),
+                smudges: vec![Smudge {
+                    cell: (0, 0),
+                    mirrored: (0, 5)
+                }],
+            }]
+        );
+        assert_eq!(
+            patterns[1].report(1),
+            vec![Reflection {
+                axis: Axis::Row(1),
+                smudges: vec![Smudge {
+                    cell: (4, 0),
+                    mirrored: (4, 1)
+                }],
+            }]
+        );
+        assert_eq!(patterns[0].smudges(Axis::Column(5)), vec![]);
+
+        let (_, patterns) = parse(
+            "#.
+.#",
+        )
+        .unwrap();
+        assert_eq!(
+            patterns[0].smudges(Axis::Column(1)),
+            vec![
+                Smudge {
+                    cell: (0, 0),
+                    mirrored: (1, 0)
+                },
+                Smudge {
+                    cell: (0, 1),
+                    mirrored: (1, 1)
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn every_matching_axis() {
+        let (_, patterns) = parse(
+            "##
+##",
+        )
+        .unwrap();
+        assert_eq!(
+            patterns[0].reflections(0),
+            vec![Axis::Row(1), Axis::Column(1)]
+        );
+        assert_eq!(patterns[0].reflections(1), vec![]);
+
+        let (_, patterns) = parse(
+            "#...
+....",
+        )
+        .unwrap();
+        assert_eq!(patterns[0].reflections(0), vec![Axis::Column(3)]);
+        assert_eq!(
+            patterns[0].reflections(1),
+            vec![Axis::Row(1), Axis::Column(1), Axis::Column(2)]
+        );
+        assert_eq!(patterns[0].reflections(2), vec![]);
+
+        let (_, patterns) = parse(
+            "#.
+.#",
+        )
+        .unwrap();
+        assert_eq!(patterns[0].reflections(0), vec![]);
+        assert_eq!(
+            patterns[0].reflections(2),
+            vec![Axis::Row(1), Axis::Column(1)]
+        );
+    }
+
+    #[test]
+    fn wide_patterns() {
+        // Mirrored between columns 50 and 51 but for a smudge past the first word
+        let row = format!("#{}#", ".".repeat(98));
+        let mut smudged = row.clone();
+        smudged.replace_range(70..71, "#");
+        let (_, patterns) = parse(&format!("{row}\n{smudged}")).unwrap();
+        assert_eq!(patterns[0].rows[0].0.len(), 2);
+        assert_eq!(patterns[0].reflections(0), vec![]);
+        assert_eq!(
+            patterns[0].reflections(1),
+            vec![Axis::Row(1), Axis::Column(50), Axis::Column(85)]
+        );
+        assert_eq!(
+            patterns[0].smudges(Axis::Row(1)),
+            vec![Smudge {
+                cell: (70, 0),
+                mirrored: (70, 1)
+            }]
+        );
+        assert_eq!(
+            patterns[0].smudges(Axis::Column(50)),
+            vec![Smudge {
+                cell: (29, 1),
+                mirrored: (70, 1)
+            }]
+        );
+    }
+
+    #[test]
+    fn ragged_pattern() {
+        let input = "#.#\n##\n#.#";
+        assert_eq!(
+            parse(input),
+            Err(nom::Err::Failure(Error::new(input, ErrorKind::Verify)))
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{Axis, Pattern, Reflection, Tile};
+
+// Each cell of the pattern, with the mirror line as a `Line` between the rows
+// or columns it separates
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Cell {
+    Tile(Tile),
+    Smudge(Tile),
+    Mirrored(Tile),
+    Line,
+}
+
+fn cells(pattern: &Pattern, reflection: &Reflection) -> Vec<Vec<Cell>> {
+    let mut rows: Vec<Vec<Cell>> = (0..pattern.height)
+        .map(|y| {
+            (0..pattern.width)
+                .map(|x| {
+                    let tile = pattern.get(x, y);
+                    if reflection.smudges.iter().any(|s| s.cell == (x, y)) {
+                        Cell::Smudge(tile)
+                    } else if reflection.smudges.iter().any(|s| s.mirrored == (x, y)) {
+                        Cell::Mirrored(tile)
+                    } else {
+                        Cell::Tile(tile)
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    match reflection.axis {
+        Axis::Row(before) => rows.insert(before, vec![Cell::Line; pattern.width]),
+        Axis::Column(before) => rows
+            .iter_mut()
+            .for_each(|row| row.insert(before, Cell::Line)),
+    }
+    rows
+}
+
+fn tile_char(tile: Tile) -> char {
+    match tile {
+        Tile::Ash => '.',
+        Tile::Rock => '#',
+    }
+}
+
+fn line_char(axis: Axis) -> char {
+    match axis {
+        Axis::Row(_) => '─',
+        Axis::Column(_) => '│',
+    }
+}
+
+pub fn to_ansi(pattern: &Pattern, reflection: &Reflection) -> String {
+    let mut out = String::new();
+    for row in cells(pattern, reflection) {
+        for cell in row {
+            let (colour, c) = match cell {
+                Cell::Tile(tile) => ("\x1b[2m", tile_char(tile)),
+                Cell::Smudge(tile) => ("\x1b[1;41m", tile_char(tile)),
+                Cell::Mirrored(tile) => ("\x1b[1;33m", tile_char(tile)),
+                Cell::Line => ("\x1b[1;36m", line_char(reflection.axis)),
+            };
+            out.push_str(colour);
+            out.push(c);
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// Uncoloured version with `*` marking the smudges
+pub fn to_text(pattern: &Pattern, reflection: &Reflection) -> String {
+    let mut out = String::new();
+    for row in cells(pattern, reflection) {
+        out.extend(row.into_iter().map(|cell| match cell {
+            Cell::Tile(tile) | Cell::Mirrored(tile) => tile_char(tile),
+            Cell::Smudge(_) => '*',
+            Cell::Line => line_char(reflection.axis),
+        }));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+
+    const EXAMPLE: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.";
+
+    #[test]
+    fn text() {
+        let (_, patterns) = parse(EXAMPLE).unwrap();
+        let pattern = &patterns[0];
+
+        let reflection = &pattern.report(0)[0];
+        assert_eq!(
+            to_text(pattern, reflection),
+            "#.##.│.##.
+..#.#│#.#.
+##...│...#
+##...│...#
+..#.#│#.#.
+..##.│.##.
+#.#.#│#.#.
+"
+        );
+
+        let reflection = &pattern.report(1)[0];
+        assert_eq!(
+            to_text(pattern, reflection),
+            "*.##..##.
+..#.##.#.
+##......#
+─────────
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+"
+        );
+    }
+}
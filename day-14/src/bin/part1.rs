@@ -0,0 +1,33 @@
+use day_14::{Direction, Platform};
+
+fn process(input: &str) -> String {
+    let mut platform = Platform::parse(input);
+    platform.tilt(Direction::North);
+    platform.north_load().to_string()
+}
+
+fn main() {
+    println!("Output: {}", process(include_str!("../input.txt")));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    const ANSWER: &str = "136";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE))
+    }
+}
@@ -0,0 +1,36 @@
+use day_14::{Direction, Platform};
+
+const CYCLES: usize = 1_000_000_000;
+
+fn process(input: &str) -> String {
+    Platform::parse(input)
+        .spin_cycles(&Direction::CYCLE, CYCLES)
+        .north_load()
+        .to_string()
+}
+
+fn main() {
+    println!("Output: {}", process(include_str!("../input.txt")));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    const ANSWER: &str = "64";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE))
+    }
+}
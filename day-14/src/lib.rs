@@ -0,0 +1,264 @@
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Tile {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'O' => Tile::Round,
+            '#' => Tile::Cube,
+            '.' => Tile::Empty,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Round => 'O',
+            Tile::Cube => '#',
+            Tile::Empty => '.',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+impl Direction {
+    // The order the platform is tilted in for one spin cycle
+    pub const CYCLE: [Direction; 4] = [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ];
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Platform {
+    pub width: usize,
+    pub height: usize,
+    pub tiles: Vec<Tile>,
+}
+
+impl Platform {
+    pub fn parse(input: &str) -> Self {
+        let rows: Vec<Vec<Tile>> = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.try_into().expect("valid platform tile"))
+                    .collect()
+            })
+            .collect();
+
+        Platform {
+            width: rows.first().map_or(0, |row| row.len()),
+            height: rows.len(),
+            tiles: rows.concat(),
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Tile {
+        self.tiles[y * self.width + x]
+    }
+
+    // Index of the tile `step` tiles away from the wall the rocks roll
+    // towards, along column or row `line`
+    fn index(&self, direction: Direction, line: usize, step: usize) -> usize {
+        let (x, y) = match direction {
+            Direction::North => (line, step),
+            Direction::South => (line, self.height - 1 - step),
+            Direction::West => (step, line),
+            Direction::East => (self.width - 1 - step, line),
+        };
+        y * self.width + x
+    }
+
+    // Walks each line from the wall, moving every round rock to the first
+    // free tile after the last cube rock or rock that came to rest
+    pub fn tilt(&mut self, direction: Direction) {
+        let (lines, len) = match direction {
+            Direction::North | Direction::South => (self.width, self.height),
+            Direction::West | Direction::East => (self.height, self.width),
+        };
+        for line in 0..lines {
+            let mut free = 0;
+            for step in 0..len {
+                let i = self.index(direction, line, step);
+                match self.tiles[i] {
+                    Tile::Cube => free = step + 1,
+                    Tile::Round => {
+                        self.tiles[i] = Tile::Empty;
+                        let rest = self.index(direction, line, free);
+                        self.tiles[rest] = Tile::Round;
+                        free += 1;
+                    }
+                    Tile::Empty => {}
+                }
+            }
+        }
+    }
+
+    pub fn spin(&mut self, sequence: &[Direction]) {
+        for direction in sequence {
+            self.tilt(*direction);
+        }
+    }
+
+    // Runs `sequence` `cycles` times. Once a platform repeats, the rest of the
+    // cycles just go round the same loop, so the result is looked up from the
+    // platforms already seen.
+    pub fn spin_cycles(&self, sequence: &[Direction], cycles: usize) -> Platform {
+        let mut seen: HashMap<Platform, usize> = HashMap::new();
+        let mut history = vec![];
+        let mut platform = self.clone();
+        for cycle in 0..cycles {
+            if let Some(&start) = seen.get(&platform) {
+                let len = cycle - start;
+                return history.swap_remove(start + (cycles - start) % len);
+            }
+            seen.insert(platform.clone(), cycle);
+            history.push(platform.clone());
+            platform.spin(sequence);
+        }
+        platform
+    }
+
+    // Each round rock adds the number of rows from it to the south edge
+    pub fn north_load(&self) -> usize {
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| **tile == Tile::Round)
+            .map(|(i, _)| self.height - i / self.width)
+            .sum()
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.tiles.chunks(self.width) {
+            writeln!(
+                f,
+                "{}",
+                row.iter().map(|tile| char::from(*tile)).collect::<String>()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn tilt_each_direction() {
+        let platform = Platform::parse("O.#\n..O\n.O.");
+        let tilted = |direction| {
+            let mut platform = platform.clone();
+            platform.tilt(direction);
+            platform.to_string()
+        };
+        assert_eq!(tilted(Direction::North), "OO#\n..O\n...\n");
+        assert_eq!(tilted(Direction::South), "..#\n...\nOOO\n");
+        assert_eq!(tilted(Direction::West), "O.#\nO..\nO..\n");
+        assert_eq!(tilted(Direction::East), ".O#\n..O\n..O\n");
+    }
+
+    #[test]
+    fn spin_cycles() {
+        let mut platform = Platform::parse(EXAMPLE);
+        platform.spin(&Direction::CYCLE);
+        assert_eq!(
+            platform.to_string(),
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+"
+        );
+        platform.spin(&Direction::CYCLE);
+        platform.spin(&Direction::CYCLE);
+        assert_eq!(
+            platform,
+            Platform::parse(EXAMPLE).spin_cycles(&Direction::CYCLE, 3)
+        );
+        assert_eq!(
+            platform.to_string(),
+            ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O
+"
+        );
+    }
+
+    #[test]
+    fn cycle_detection_matches_spinning() {
+        let platform = Platform::parse(EXAMPLE);
+        let sequences = [
+            Direction::CYCLE.to_vec(),
+            vec![Direction::East, Direction::North],
+            vec![Direction::South],
+        ];
+        for sequence in sequences {
+            let mut spun = platform.clone();
+            for cycles in 0..40 {
+                assert_eq!(platform.spin_cycles(&sequence, cycles), spun);
+                spun.spin(&sequence);
+            }
+        }
+    }
+
+    #[test]
+    fn load() {
+        let mut platform = Platform::parse(EXAMPLE);
+        platform.tilt(Direction::North);
+        assert_eq!(platform.north_load(), 136);
+        assert_eq!(
+            Platform::parse(EXAMPLE)
+                .spin_cycles(&Direction::CYCLE, 1_000_000_000)
+                .north_load(),
+            64
+        );
+    }
+}
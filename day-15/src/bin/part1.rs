@@ -0,0 +1,27 @@
+use day_15::hash;
+
+fn process(input: &str) -> String {
+    input
+        .trim()
+        .split(',')
+        .map(|step| hash(step) as u32)
+        .sum::<u32>()
+        .to_string()
+}
+
+fn main() {
+    println!("Output: {}", process(include_str!("../input.txt")));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    const ANSWER: &str = "1320";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE))
+    }
+}
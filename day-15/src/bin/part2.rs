@@ -0,0 +1,27 @@
+use day_15::{parse, LensBoxes};
+
+fn process(input: &str) -> String {
+    let steps = parse(input).unwrap();
+    let mut boxes = LensBoxes::new();
+    for step in &steps {
+        boxes.apply(step);
+    }
+    boxes.focusing_power().to_string()
+}
+
+fn main() {
+    println!("Output: {}", process(include_str!("../input.txt")));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    const ANSWER: &str = "145";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE))
+    }
+}
@@ -0,0 +1,24 @@
+use day_15::{parse, trace};
+
+// Usage: trace [input file]
+fn main() -> std::io::Result<()> {
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("../input.txt").to_string(),
+    };
+    let steps = match parse(&input) {
+        Ok(steps) => steps,
+        Err(error) => {
+            eprintln!("error: {error}");
+            std::process::exit(1);
+        }
+    };
+
+    let mut power = 0;
+    for (step, boxes) in trace(&steps) {
+        println!("After \"{step}\":\n{boxes}");
+        power = boxes.focusing_power();
+    }
+    println!("Output: {power}");
+    Ok(())
+}
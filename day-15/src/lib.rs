@@ -0,0 +1,251 @@
+use std::fmt;
+
+// The Holiday ASCII String Helper algorithm
+pub fn hash(s: &str) -> u8 {
+    s.bytes()
+        .fold(0u8, |hash, c| hash.wrapping_add(c).wrapping_mul(17))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    Remove,
+    Insert(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Step<'a> {
+    pub label: &'a str,
+    pub operation: Operation,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    MissingOperation(String),
+    InvalidFocalLength(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingOperation(step) => write!(f, "step {step:?} has no - or ="),
+            Error::InvalidFocalLength(step) => {
+                write!(f, "step {step:?} needs a focal length from 1 to 9")
+            }
+        }
+    }
+}
+
+impl<'a> Step<'a> {
+    pub fn parse(step: &'a str) -> Result<Self, Error> {
+        if let Some(label) = step.strip_suffix('-') {
+            return Ok(Step {
+                label,
+                operation: Operation::Remove,
+            });
+        }
+        let (label, focal_length) = step
+            .split_once('=')
+            .ok_or_else(|| Error::MissingOperation(step.to_string()))?;
+        match focal_length.parse() {
+            Ok(focal_length @ 1..=9) => Ok(Step {
+                label,
+                operation: Operation::Insert(focal_length),
+            }),
+            _ => Err(Error::InvalidFocalLength(step.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.operation {
+            Operation::Remove => write!(f, "{}-", self.label),
+            Operation::Insert(focal_length) => write!(f, "{}={focal_length}", self.label),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step<'_>>, Error> {
+    input.trim().split(',').map(Step::parse).collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Lens<'a> {
+    pub label: &'a str,
+    pub focal_length: u8,
+}
+
+// 256 boxes, each keeping its lenses in the order they were put in
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LensBoxes<'a> {
+    boxes: Vec<Vec<Lens<'a>>>,
+}
+
+impl Default for LensBoxes<'_> {
+    fn default() -> Self {
+        LensBoxes {
+            boxes: vec![vec![]; 256],
+        }
+    }
+}
+
+impl<'a> LensBoxes<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, index: u8) -> &[Lens<'a>] {
+        &self.boxes[index as usize]
+    }
+
+    pub fn remove(&mut self, label: &str) -> Option<Lens<'a>> {
+        let lenses = &mut self.boxes[hash(label) as usize];
+        let position = lenses.iter().position(|lens| lens.label == label)?;
+        Some(lenses.remove(position))
+    }
+
+    // Replaces the lens with the same label in place, or puts the lens behind
+    // the others in its box
+    pub fn insert(&mut self, label: &'a str, focal_length: u8) {
+        let lenses = &mut self.boxes[hash(label) as usize];
+        match lenses.iter_mut().find(|lens| lens.label == label) {
+            Some(lens) => lens.focal_length = focal_length,
+            None => lenses.push(Lens {
+                label,
+                focal_length,
+            }),
+        }
+    }
+
+    pub fn apply(&mut self, step: &Step<'a>) {
+        match step.operation {
+            Operation::Remove => {
+                self.remove(step.label);
+            }
+            Operation::Insert(focal_length) => self.insert(step.label, focal_length),
+        }
+    }
+
+    pub fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(i, lenses)| {
+                lenses
+                    .iter()
+                    .enumerate()
+                    .map(move |(slot, lens)| (i + 1) * (slot + 1) * lens.focal_length as usize)
+            })
+            .sum()
+    }
+}
+
+// Formatted like the puzzle, only listing the boxes holding lenses
+impl fmt::Display for LensBoxes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, lenses) in self.boxes.iter().enumerate() {
+            if lenses.is_empty() {
+                continue;
+            }
+            write!(f, "Box {i}:")?;
+            for lens in lenses {
+                write!(f, " [{} {}]", lens.label, lens.focal_length)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// The boxes after each step is applied
+pub fn trace<'a>(steps: &'a [Step<'a>]) -> impl Iterator<Item = (Step<'a>, LensBoxes<'a>)> {
+    steps.iter().scan(LensBoxes::new(), |boxes, step| {
+        boxes.apply(step);
+        Some((*step, boxes.clone()))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn hashes() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn"), 0);
+        assert_eq!(hash("qp"), 1);
+        assert_eq!(hash(""), 0);
+    }
+
+    #[test]
+    fn parse_steps() {
+        let steps = parse(EXAMPLE).unwrap();
+        assert_eq!(steps.len(), 11);
+        assert_eq!(
+            steps[1],
+            Step {
+                label: "cm",
+                operation: Operation::Remove
+            }
+        );
+        assert_eq!(steps[3].to_string(), "cm=2");
+        assert_eq!(
+            parse("ab=0"),
+            Err(Error::InvalidFocalLength("ab=0".to_string()))
+        );
+        assert_eq!(
+            parse("ab=10"),
+            Err(Error::InvalidFocalLength("ab=10".to_string()))
+        );
+        assert_eq!(
+            parse("rn=1,ab"),
+            Err(Error::MissingOperation("ab".to_string()))
+        );
+    }
+
+    #[test]
+    fn insertion_order() {
+        let mut boxes = LensBoxes::new();
+        boxes.insert("pc", 4);
+        boxes.insert("ot", 9);
+        boxes.insert("pc", 6);
+        assert_eq!(boxes.to_string(), "Box 3: [pc 6] [ot 9]\n");
+        assert_eq!(
+            boxes.remove("pc"),
+            Some(Lens {
+                label: "pc",
+                focal_length: 6
+            })
+        );
+        assert_eq!(boxes.remove("pc"), None);
+        boxes.insert("pc", 1);
+        assert_eq!(boxes.get(3).len(), 2);
+        assert_eq!(boxes.to_string(), "Box 3: [ot 9] [pc 1]\n");
+    }
+
+    #[test]
+    fn trace_example() {
+        let steps = parse(EXAMPLE).unwrap();
+        let trace: Vec<String> = trace(&steps)
+            .map(|(step, boxes)| format!("After \"{step}\":\n{boxes}"))
+            .collect();
+        assert_eq!(trace[0], "After \"rn=1\":\nBox 0: [rn 1]\n");
+        assert_eq!(trace[1], "After \"cm-\":\nBox 0: [rn 1]\n");
+        assert_eq!(trace[2], "After \"qp=3\":\nBox 0: [rn 1]\nBox 1: [qp 3]\n");
+        assert_eq!(
+            trace[10],
+            "After \"ot=7\":\nBox 0: [rn 1] [cm 2]\nBox 3: [ot 7] [ab 5] [pc 6]\n"
+        );
+    }
+
+    #[test]
+    fn focusing_power() {
+        let mut boxes = LensBoxes::new();
+        for step in parse(EXAMPLE).unwrap() {
+            boxes.apply(&step);
+        }
+        assert_eq!(boxes.focusing_power(), 145);
+    }
+}
@@ -0,0 +1,37 @@
+use day_16::{Beam, Contraption, Direction};
+
+fn process(input: &str) -> String {
+    Contraption::parse(input)
+        .energized(Beam {
+            x: 0,
+            y: 0,
+            direction: Direction::East,
+        })
+        .to_string()
+}
+
+fn main() {
+    println!("Output: {}", process(include_str!("../input.txt")));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+    const ANSWER: &str = "46";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE))
+    }
+}
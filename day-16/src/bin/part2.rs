@@ -0,0 +1,35 @@
+use day_16::{energizer::Energizer, Contraption};
+
+fn process(input: &str) -> String {
+    let contraption = Contraption::parse(input);
+    let (_, energized) = Energizer::new(&contraption)
+        .best_entry()
+        .expect("contraption to have an edge");
+    energized.to_string()
+}
+
+fn main() {
+    println!("Output: {}", process(include_str!("../input.txt")));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+    const ANSWER: &str = "51";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE))
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Beam, Contraption, Direction, Outcome, Tile};
+
+// One bit per tile of the contraption
+#[derive(Debug, Clone)]
+struct TileSet {
+    words: Vec<u64>,
+}
+
+impl TileSet {
+    fn new(tiles: usize) -> Self {
+        TileSet {
+            words: vec![0; tiles.div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, tile: usize) {
+        self.words[tile / 64] |= 1 << (tile % 64);
+    }
+
+    fn union_with(&mut self, other: &TileSet) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+}
+
+// Follows a beam until a splitter splits it, returning the tiles it passed
+// and the splitter it ended at, if any. Without splitting a beam can only
+// go in one direction, so it either leaves the contraption or goes round a
+// loop of mirrors.
+fn follow(contraption: &Contraption, start: Beam) -> (Vec<usize>, Option<usize>) {
+    let mut tiles = vec![];
+    let mut seen = HashSet::new();
+    let mut beam = Some(start);
+    while let Some(current) = beam {
+        if !seen.insert(current) {
+            break;
+        }
+        let tile = contraption.index(&current);
+        tiles.push(tile);
+        beam = match contraption.get(&current).outcome(current.direction) {
+            Outcome::Continue(direction) => contraption.advance(&current, direction),
+            Outcome::Split(..) => return (tiles, Some(tile)),
+        };
+    }
+    (tiles, None)
+}
+
+// Splitters form a graph, where a splitter leads to the ones its two beams
+// are split by next. Splitters in the same strongly connected component
+// energize the same tiles, so each component's tiles are worked out once
+// from the components it leads to and shared between every entry.
+pub struct Energizer<'a> {
+    contraption: &'a Contraption,
+    node_of: HashMap<usize, usize>,
+    component_of: Vec<usize>,
+    energized: Vec<TileSet>,
+}
+
+// Tarjan's algorithm, which finishes components only after every component
+// they lead to
+struct Components<'a> {
+    edges: &'a [Vec<usize>],
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl Components<'_> {
+    fn find(edges: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let mut components = Components {
+            edges,
+            index: vec![None; edges.len()],
+            low: vec![0; edges.len()],
+            on_stack: vec![false; edges.len()],
+            stack: vec![],
+            next: 0,
+            components: vec![],
+        };
+        for node in 0..edges.len() {
+            if components.index[node].is_none() {
+                components.visit(node);
+            }
+        }
+        components.components
+    }
+
+    fn visit(&mut self, node: usize) {
+        self.index[node] = Some(self.next);
+        self.low[node] = self.next;
+        self.next += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+
+        for &next in &self.edges[node] {
+            match self.index[next] {
+                None => {
+                    self.visit(next);
+                    self.low[node] = self.low[node].min(self.low[next]);
+                }
+                Some(index) if self.on_stack[next] => {
+                    self.low[node] = self.low[node].min(index);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low[node]) == self.index[node] {
+            let mut component = vec![];
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+impl<'a> Energizer<'a> {
+    pub fn new(contraption: &'a Contraption) -> Self {
+        let splitters: Vec<Beam> = (0..contraption.height)
+            .flat_map(|y| (0..contraption.width).map(move |x| (x, y)))
+            .filter_map(|(x, y)| {
+                // Heading into the flat side, so the beam is split
+                let direction = match contraption.tiles[y * contraption.width + x] {
+                    Tile::VerticalSplit => Direction::East,
+                    Tile::HorizontalSplit => Direction::North,
+                    _ => return None,
+                };
+                Some(Beam { x, y, direction })
+            })
+            .collect();
+        let node_of: HashMap<usize, usize> = splitters
+            .iter()
+            .enumerate()
+            .map(|(node, splitter)| (contraption.index(splitter), node))
+            .collect();
+
+        // The tiles each splitter's own beams pass, and the splitters they end at
+        let mut own = vec![];
+        let mut edges = vec![];
+        for splitter in &splitters {
+            let mut tiles = TileSet::new(contraption.tiles.len());
+            tiles.insert(contraption.index(splitter));
+            let mut next = vec![];
+
+            let Outcome::Split(a, b) = contraption.get(splitter).outcome(splitter.direction) else {
+                unreachable!("splitters to split beams hitting their flat side");
+            };
+            for direction in [a, b] {
+                let Some(beam) = contraption.advance(splitter, direction) else {
+                    continue;
+                };
+                let (path, end) = follow(contraption, beam);
+                path.into_iter().for_each(|tile| tiles.insert(tile));
+                next.extend(end.map(|end| node_of[&end]));
+            }
+            own.push(tiles);
+            edges.push(next);
+        }
+
+        let components = Components::find(&edges);
+        let mut component_of = vec![0; splitters.len()];
+        let mut energized: Vec<TileSet> = vec![];
+        for (component, members) in components.iter().enumerate() {
+            members
+                .iter()
+                .for_each(|member| component_of[*member] = component);
+
+            let mut tiles = TileSet::new(contraption.tiles.len());
+            for member in members {
+                tiles.union_with(&own[*member]);
+                for next in &edges[*member] {
+                    // Every other component reached is finished already
+                    if component_of[*next] != component {
+                        tiles.union_with(&energized[component_of[*next]]);
+                    }
+                }
+            }
+            energized.push(tiles);
+        }
+
+        Energizer {
+            contraption,
+            node_of,
+            component_of,
+            energized,
+        }
+    }
+
+    pub fn energized(&self, entry: Beam) -> usize {
+        let (path, end) = follow(self.contraption, entry);
+        let mut tiles = match end {
+            Some(end) => self.energized[self.component_of[self.node_of[&end]]].clone(),
+            None => TileSet::new(self.contraption.tiles.len()),
+        };
+        path.into_iter().for_each(|tile| tiles.insert(tile));
+        tiles.len()
+    }
+
+    // The entry from the edge energizing the most tiles
+    pub fn best_entry(&self) -> Option<(Beam, usize)> {
+        self.contraption
+            .entries()
+            .into_iter()
+            .map(|entry| (entry, self.energized(entry)))
+            .max_by_key(|(_, energized)| *energized)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    fn assert_matches_walking(contraption: &Contraption) {
+        let energizer = Energizer::new(contraption);
+        for entry in contraption.entries() {
+            assert_eq!(
+                energizer.energized(entry),
+                contraption.energized(entry),
+                "{entry:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn best_entry() {
+        let contraption = Contraption::parse(EXAMPLE);
+        assert_matches_walking(&contraption);
+        assert_eq!(
+            Energizer::new(&contraption).best_entry(),
+            Some((
+                Beam {
+                    x: 3,
+                    y: 0,
+                    direction: Direction::South
+                },
+                51
+            ))
+        );
+    }
+
+    #[test]
+    fn loops() {
+        // Splitters feeding each other, and a loop of mirrors that a beam
+        // only passes through
+        assert_matches_walking(&Contraption::parse(
+            r"..........
+.-...|....
+..........
+.|...-....
+..........
+./..\.....
+..........
+.\../..|..
+..........",
+        ));
+        assert_matches_walking(&Contraption::parse(
+            r"/.\.
+....
+\./.
+....",
+        ));
+    }
+}
@@ -0,0 +1,243 @@
+pub mod energizer;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Empty,
+    // `/`
+    UpMirror,
+    // `\`
+    DownMirror,
+    VerticalSplit,
+    HorizontalSplit,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '.' => Tile::Empty,
+            '/' => Tile::UpMirror,
+            '\\' => Tile::DownMirror,
+            '|' => Tile::VerticalSplit,
+            '-' => Tile::HorizontalSplit,
+            _ => return Err(()),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    fn bit(&self) -> u8 {
+        1 << *self as u8
+    }
+}
+
+// Where a beam heading `direction` goes after passing through a tile
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    Continue(Direction),
+    Split(Direction, Direction),
+}
+
+impl Tile {
+    pub fn outcome(&self, direction: Direction) -> Outcome {
+        use Direction::*;
+        match (self, direction) {
+            (Tile::Empty, _) => Outcome::Continue(direction),
+            (Tile::UpMirror, North) | (Tile::DownMirror, South) => Outcome::Continue(East),
+            (Tile::UpMirror, East) | (Tile::DownMirror, West) => Outcome::Continue(North),
+            (Tile::UpMirror, South) | (Tile::DownMirror, North) => Outcome::Continue(West),
+            (Tile::UpMirror, West) | (Tile::DownMirror, East) => Outcome::Continue(South),
+            (Tile::VerticalSplit, North | South) | (Tile::HorizontalSplit, East | West) => {
+                Outcome::Continue(direction)
+            }
+            (Tile::VerticalSplit, East | West) => Outcome::Split(North, South),
+            (Tile::HorizontalSplit, North | South) => Outcome::Split(East, West),
+        }
+    }
+}
+
+// A beam entering the tile at `x`, `y` heading `direction`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Beam {
+    pub x: usize,
+    pub y: usize,
+    pub direction: Direction,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Contraption {
+    pub width: usize,
+    pub height: usize,
+    pub tiles: Vec<Tile>,
+}
+
+impl Contraption {
+    pub fn parse(input: &str) -> Self {
+        let rows: Vec<Vec<Tile>> = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.try_into().expect("valid contraption tile"))
+                    .collect()
+            })
+            .collect();
+
+        Contraption {
+            width: rows.first().map_or(0, |row| row.len()),
+            height: rows.len(),
+            tiles: rows.concat(),
+        }
+    }
+
+    pub fn index(&self, beam: &Beam) -> usize {
+        beam.y * self.width + beam.x
+    }
+
+    pub fn get(&self, beam: &Beam) -> Tile {
+        self.tiles[self.index(beam)]
+    }
+
+    // The beam leaving the tile of `beam` heading `direction`, None once it
+    // leaves the contraption
+    pub fn advance(&self, beam: &Beam, direction: Direction) -> Option<Beam> {
+        let (x, y) = match direction {
+            Direction::North => (beam.x, beam.y.checked_sub(1)?),
+            Direction::East => (beam.x + 1, beam.y),
+            Direction::South => (beam.x, beam.y + 1),
+            Direction::West => (beam.x.checked_sub(1)?, beam.y),
+        };
+        (x < self.width && y < self.height).then_some(Beam { x, y, direction })
+    }
+
+    // Every beam entering from the edge, heading away from it
+    pub fn entries(&self) -> Vec<Beam> {
+        let columns = (0..self.width).flat_map(|x| {
+            [
+                Beam {
+                    x,
+                    y: 0,
+                    direction: Direction::South,
+                },
+                Beam {
+                    x,
+                    y: self.height - 1,
+                    direction: Direction::North,
+                },
+            ]
+        });
+        let rows = (0..self.height).flat_map(|y| {
+            [
+                Beam {
+                    x: 0,
+                    y,
+                    direction: Direction::East,
+                },
+                Beam {
+                    x: self.width - 1,
+                    y,
+                    direction: Direction::West,
+                },
+            ]
+        });
+        columns.chain(rows).collect()
+    }
+
+    // Follows every beam, skipping any tile already entered in the same
+    // direction so loops end
+    pub fn energized(&self, entry: Beam) -> usize {
+        let mut seen = vec![0u8; self.tiles.len()];
+        let mut beams = vec![entry];
+        while let Some(beam) = beams.pop() {
+            let seen = &mut seen[self.index(&beam)];
+            if *seen & beam.direction.bit() != 0 {
+                continue;
+            }
+            *seen |= beam.direction.bit();
+
+            let directions = match self.get(&beam).outcome(beam.direction) {
+                Outcome::Continue(direction) => vec![direction],
+                Outcome::Split(a, b) => vec![a, b],
+            };
+            beams.extend(
+                directions
+                    .into_iter()
+                    .filter_map(|direction| self.advance(&beam, direction)),
+            );
+        }
+        seen.into_iter().filter(|seen| *seen != 0).count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn mirrors() {
+        assert_eq!(
+            Tile::UpMirror.outcome(Direction::East),
+            Outcome::Continue(Direction::North)
+        );
+        assert_eq!(
+            Tile::DownMirror.outcome(Direction::East),
+            Outcome::Continue(Direction::South)
+        );
+        assert_eq!(
+            Tile::VerticalSplit.outcome(Direction::West),
+            Outcome::Split(Direction::North, Direction::South)
+        );
+        assert_eq!(
+            Tile::HorizontalSplit.outcome(Direction::West),
+            Outcome::Continue(Direction::West)
+        );
+    }
+
+    #[test]
+    fn energized_example() {
+        let contraption = Contraption::parse(EXAMPLE);
+        assert_eq!(contraption.entries().len(), 40);
+        assert_eq!(
+            contraption.energized(Beam {
+                x: 0,
+                y: 0,
+                direction: Direction::East
+            }),
+            46
+        );
+        assert_eq!(
+            contraption.energized(Beam {
+                x: 3,
+                y: 0,
+                direction: Direction::South
+            }),
+            51
+        );
+    }
+}
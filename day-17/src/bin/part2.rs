@@ -0,0 +1,43 @@
+use day_17::{City, Crucible};
+
+fn process(input: &str) -> String {
+    City::parse(input)
+        .least_heat_loss(Crucible::ULTRA)
+        .expect("crucible to reach the factory")
+        .heat_loss
+        .to_string()
+}
+
+// The puzzle input isn't checked in for this day, so it's read when run
+fn main() -> std::io::Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt").to_string());
+    println!("Output: {}", process(&std::fs::read_to_string(path)?));
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+    const ANSWER: &str = "94";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE))
+    }
+}
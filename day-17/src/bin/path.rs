@@ -0,0 +1,31 @@
+use day_17::{City, Crucible};
+
+// Usage: path [input file] [--ultra] [--min <n>] [--max <n>]
+fn main() -> std::io::Result<()> {
+    let mut input_path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt").to_string();
+    let mut crucible = Crucible::NORMAL;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut run = || -> u32 {
+            args.next()
+                .and_then(|run| run.parse().ok())
+                .unwrap_or_else(|| panic!("{arg} to be followed by a run length"))
+        };
+        match arg.as_str() {
+            "--ultra" => crucible = Crucible::ULTRA,
+            "--min" => crucible.min_run = run(),
+            "--max" => crucible.max_run = run(),
+            _ => input_path = arg,
+        }
+    }
+
+    let city = City::parse(&std::fs::read_to_string(input_path)?);
+    match city.least_heat_loss(crucible) {
+        Some(path) => {
+            print!("{}", city.render(&path));
+            println!("Output: {}", path.heat_loss);
+        }
+        None => println!("no path for a crucible running {crucible:?}"),
+    }
+    Ok(())
+}
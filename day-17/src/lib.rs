@@ -0,0 +1,341 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub fn right(&self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn left(&self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+// How many blocks a crucible has to move in a straight line before it can
+// turn or stop, and how many it can move before it has to turn
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Crucible {
+    pub min_run: u32,
+    pub max_run: u32,
+}
+
+impl Crucible {
+    pub const NORMAL: Crucible = Crucible {
+        min_run: 0,
+        max_run: 3,
+    };
+    pub const ULTRA: Crucible = Crucible {
+        min_run: 4,
+        max_run: 10,
+    };
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct City {
+    pub width: usize,
+    pub height: usize,
+    pub heat_loss: Vec<u32>,
+}
+
+// The blocks a crucible moves through, in order, without the starting block
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Path {
+    pub heat_loss: u32,
+    pub steps: Vec<(usize, usize, Direction)>,
+}
+
+// A crucible at a block, having moved `run` blocks heading `direction`
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct State {
+    x: usize,
+    y: usize,
+    direction: Direction,
+    run: u32,
+}
+
+impl City {
+    pub fn parse(input: &str) -> Self {
+        let rows: Vec<Vec<u32>> = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.to_digit(10).expect("heat loss to be a digit"))
+                    .collect()
+            })
+            .collect();
+
+        City {
+            width: rows.first().map_or(0, |row| row.len()),
+            height: rows.len(),
+            heat_loss: rows.concat(),
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> u32 {
+        self.heat_loss[y * self.width + x]
+    }
+
+    fn advance(&self, state: &State, direction: Direction) -> Option<State> {
+        let (x, y) = match direction {
+            Direction::North => (state.x, state.y.checked_sub(1)?),
+            Direction::East => (state.x + 1, state.y),
+            Direction::South => (state.x, state.y + 1),
+            Direction::West => (state.x.checked_sub(1)?, state.y),
+        };
+        let run = if direction == state.direction {
+            state.run + 1
+        } else {
+            1
+        };
+        (x < self.width && y < self.height).then_some(State {
+            x,
+            y,
+            direction,
+            run,
+        })
+    }
+
+    fn next_states(&self, state: &State, crucible: Crucible) -> impl Iterator<Item = State> + '_ {
+        let straight = (state.run < crucible.max_run).then_some(state.direction);
+        let turns = (state.run >= crucible.min_run)
+            .then_some([state.direction.left(), state.direction.right()])
+            .into_iter()
+            .flatten();
+        let state = *state;
+        straight
+            .into_iter()
+            .chain(turns)
+            .filter_map(move |direction| self.advance(&state, direction))
+    }
+
+    fn state_index(&self, state: &State, crucible: Crucible) -> usize {
+        ((state.y * self.width + state.x) * 4 + state.direction as usize)
+            * (crucible.max_run as usize + 1)
+            + state.run as usize
+    }
+
+    // A* from the top left to the bottom right block, using the distance
+    // left times the least heat any block loses as the estimate. None for an
+    // empty city.
+    pub fn least_heat_loss(&self, crucible: Crucible) -> Option<Path> {
+        let min_heat = *self.heat_loss.iter().min()?;
+        let target = (self.width - 1, self.height - 1);
+        let estimate = |state: &State| (target.0 - state.x + target.1 - state.y) as u32 * min_heat;
+        let states = self.width * self.height * 4 * (crucible.max_run as usize + 1);
+        let mut best = vec![u32::MAX; states];
+        let mut previous: Vec<Option<State>> = vec![None; states];
+
+        let mut queue = BinaryHeap::new();
+        for direction in [Direction::East, Direction::South] {
+            let start = State {
+                x: 0,
+                y: 0,
+                direction,
+                run: 0,
+            };
+            best[self.state_index(&start, crucible)] = 0;
+            queue.push(Reverse((estimate(&start), 0, start)));
+        }
+
+        while let Some(Reverse((_, heat_loss, state))) = queue.pop() {
+            if heat_loss > best[self.state_index(&state, crucible)] {
+                continue;
+            }
+            if (state.x, state.y) == target && state.run >= crucible.min_run {
+                return Some(self.path(state, heat_loss, &previous, crucible));
+            }
+            for next in self.next_states(&state, crucible) {
+                let next_heat_loss = heat_loss + self.get(next.x, next.y);
+                let index = self.state_index(&next, crucible);
+                if next_heat_loss < best[index] {
+                    best[index] = next_heat_loss;
+                    previous[index] = Some(state);
+                    queue.push(Reverse((
+                        next_heat_loss + estimate(&next),
+                        next_heat_loss,
+                        next,
+                    )));
+                }
+            }
+        }
+        None
+    }
+
+    fn path(
+        &self,
+        end: State,
+        heat_loss: u32,
+        previous: &[Option<State>],
+        crucible: Crucible,
+    ) -> Path {
+        let mut steps = vec![];
+        let mut state = Some(end);
+        while let Some(current) = state.filter(|state| state.run > 0) {
+            steps.push((current.x, current.y, current.direction));
+            state = previous[self.state_index(&current, crucible)];
+        }
+        steps.reverse();
+        Path { heat_loss, steps }
+    }
+
+    // The city with the path drawn over it, as in the puzzle
+    pub fn render(&self, path: &Path) -> String {
+        let mut grid: Vec<Vec<char>> = self
+            .heat_loss
+            .chunks(self.width)
+            .map(|row| {
+                row.iter()
+                    .map(|heat_loss| char::from_digit(*heat_loss, 10).unwrap())
+                    .collect()
+            })
+            .collect();
+        for (x, y, direction) in &path.steps {
+            grid[*y][*x] = direction.arrow();
+        }
+        grid.into_iter()
+            .map(|row| row.into_iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    // Checks the path is made of single steps that follow the crucible's
+    // rules and add up to its heat loss
+    fn assert_valid(city: &City, path: &Path, crucible: Crucible) {
+        let mut position = (0, 0);
+        let mut runs = vec![];
+        let mut last = None;
+        for &(x, y, direction) in &path.steps {
+            let moved = match direction {
+                Direction::North => (position.0, position.1 - 1),
+                Direction::East => (position.0 + 1, position.1),
+                Direction::South => (position.0, position.1 + 1),
+                Direction::West => (position.0 - 1, position.1),
+            };
+            assert_eq!(moved, (x, y));
+            position = moved;
+
+            if last == Some(direction) {
+                *runs.last_mut().unwrap() += 1;
+            } else {
+                runs.push(1);
+            }
+            last = Some(direction);
+        }
+        assert_eq!(position, (city.width - 1, city.height - 1));
+        assert!(runs
+            .iter()
+            .all(|run| (crucible.min_run..=crucible.max_run).contains(run)));
+        assert_eq!(
+            path.steps
+                .iter()
+                .map(|(x, y, _)| city.get(*x, *y))
+                .sum::<u32>(),
+            path.heat_loss
+        );
+    }
+
+    #[test]
+    fn normal_crucible() {
+        let city = City::parse(EXAMPLE);
+        let path = city.least_heat_loss(Crucible::NORMAL).unwrap();
+        assert_eq!(path.heat_loss, 102);
+        assert_valid(&city, &path, Crucible::NORMAL);
+    }
+
+    #[test]
+    fn ultra_crucible() {
+        let city = City::parse(EXAMPLE);
+        let path = city.least_heat_loss(Crucible::ULTRA).unwrap();
+        assert_eq!(path.heat_loss, 94);
+        assert_valid(&city, &path, Crucible::ULTRA);
+
+        let city = City::parse(
+            "111111111111
+999999999991
+999999999991
+999999999991
+999999999991",
+        );
+        let path = city.least_heat_loss(Crucible::ULTRA).unwrap();
+        assert_eq!(path.heat_loss, 71);
+        assert_valid(&city, &path, Crucible::ULTRA);
+        assert_eq!(
+            city.render(&path),
+            "1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+"
+        );
+    }
+
+    #[test]
+    fn unreachable_end() {
+        let city = City::parse("11\n11");
+        assert_eq!(city.least_heat_loss(Crucible::ULTRA), None);
+        assert_eq!(
+            city.least_heat_loss(Crucible {
+                min_run: 0,
+                max_run: 1
+            })
+            .map(|path| path.heat_loss),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn zero_heat_loss() {
+        let city = City::parse("19\n00");
+        assert_eq!(
+            city.least_heat_loss(Crucible::NORMAL)
+                .map(|path| path.heat_loss),
+            Some(0)
+        );
+        assert_eq!(City::parse("").least_heat_loss(Crucible::NORMAL), None);
+    }
+}
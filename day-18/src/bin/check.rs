@@ -0,0 +1,16 @@
+use day_18::plan::parse_checked;
+
+// Usage: check [input file]
+fn main() -> std::io::Result<()> {
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => include_str!("./input.txt").to_string(),
+    };
+    match parse_checked(&input) {
+        Ok((instructions, winding)) => {
+            println!("{} instructions dug {winding:?}", instructions.len())
+        }
+        Err(issues) => issues.iter().for_each(|issue| println!("{issue}")),
+    }
+    Ok(())
+}
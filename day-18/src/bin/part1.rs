@@ -1,171 +1,14 @@
-use std::collections::HashSet;
-
-use itertools::Itertools;
-use nom::{
-    character::complete::{self, hex_digit1, multispace1, one_of, space1},
-    multi::separated_list1,
-    sequence::tuple,
-    IResult, Parser,
-};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl TryFrom<char> for Direction {
-    type Error = ();
-
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        Ok(match value {
-            'U' | 'u' => Direction::Up,
-            'D' | 'd' => Direction::Down,
-            'L' | 'l' => Direction::Left,
-            'R' | 'r' => Direction::Right,
-            _ => return Err(()),
-        })
-    }
-}
-
-impl std::ops::Not for Direction {
-    type Output = Direction;
-
-    fn not(self) -> Self::Output {
-        match self {
-            Self::Down => Self::Up,
-            Self::Up => Self::Down,
-            Self::Left => Self::Right,
-            Self::Right => Self::Left,
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Instruction {
-    direction: Direction,
-    steps: u8,
-    color: (u8, u8, u8),
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Coord {
-    x: isize,
-    y: isize,
-}
+use day_18::plan::{dig, parse_checked};
 
 fn process(input: &str) -> String {
-    let (_, instructions) = parse(input).unwrap();
-    dbg!(&instructions);
-
-    let mut trench: HashSet<Coord> = HashSet::new();
-    let mut pos = Coord { x: 0, y: 0 };
-    let mut left_turns = 0;
-
-    for instruction in instructions {
-        for _ in 0..instruction.steps {
-            trench.insert(pos);
-            match instruction.direction {
-                Direction::Down => pos.y += 1,
-                Direction::Up => pos.y -= 1,
-                Direction::Left => pos.x -= 1,
-                Direction::Right => pos.x += 1,
-            }
+    let (instructions, winding) = match parse_checked(input) {
+        Ok(plan) => plan,
+        Err(issues) => {
+            issues.iter().for_each(|issue| eprintln!("{issue}"));
+            std::process::exit(1);
         }
-    }
-
-    dbg!(&trench, trench.len());
-
-    let min_x = trench.iter().map(|coord| coord.x).min().unwrap();
-    let max_x = trench.iter().map(|coord| coord.x).max().unwrap();
-    let min_y = trench.iter().map(|coord| coord.y).min().unwrap();
-    let max_y = trench.iter().map(|coord| coord.y).max().unwrap();
-
-    dbg!(min_x, max_x, min_y, max_y);
-
-    let mut todo: Vec<(Coord, Direction)> = Vec::from(&[(Coord { x: 1, y: 1 }, Direction::Right)]);
-
-    while let Some((coord, from)) = todo.pop() {
-        dbg!(todo.len());
-
-        trench.insert(coord);
-
-        for direction in [
-            Direction::Down,
-            Direction::Up,
-            Direction::Left,
-            Direction::Right,
-        ] {
-            if !from == direction {
-                continue;
-            }
-            let next = match direction {
-                Direction::Down => Coord {
-                    x: coord.x,
-                    y: coord.y + 1,
-                },
-                Direction::Up => Coord {
-                    x: coord.x,
-                    y: coord.y - 1,
-                },
-                Direction::Left => Coord {
-                    x: coord.x - 1,
-                    y: coord.y,
-                },
-                Direction::Right => Coord {
-                    x: coord.x + 1,
-                    y: coord.y,
-                },
-            };
-            if trench.contains(&next) {
-                continue;
-            }
-            todo.push((next, direction));
-        }
-    }
-
-    for y in min_y..=max_y {
-        for x in min_x..=max_x {
-            print!(
-                "{}",
-                trench
-                    .contains(&Coord { x, y })
-                    .then_some('#')
-                    .unwrap_or('.')
-            );
-        }
-        println!("");
-    }
-
-    trench.len().to_string()
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<Instruction>> {
-    separated_list1(
-        multispace1,
-        tuple((
-            one_of("UDLR").map(|c| c.try_into().unwrap()),
-            space1,
-            complete::u8,
-            tuple((space1, complete::char('('), complete::char('#'))),
-            hex_digit1.map(|colorcode: &str| {
-                (0..6)
-                    .step_by(2)
-                    .map(|i| u8::from_str_radix(&colorcode[i..i + 2], 16).unwrap())
-                    .collect_tuple()
-                    .unwrap()
-            }),
-            complete::char(')'),
-        ))
-        .map(|(direction, _, steps, _, color, _)| Instruction {
-            direction,
-            steps,
-            color,
-        }),
-    )
-    .parse(input)
+    };
+    dig(&instructions, winding).len().to_string()
 }
 
 fn main() {
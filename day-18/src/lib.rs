@@ -0,0 +1,215 @@
+use itertools::Itertools;
+use nom::{
+    character::complete::{self, hex_digit1, multispace1, one_of, space1},
+    multi::separated_list1,
+    sequence::tuple,
+    IResult, Parser,
+};
+
+pub mod plan;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'U' | 'u' => Direction::Up,
+            'D' | 'd' => Direction::Down,
+            'L' | 'l' => Direction::Left,
+            'R' | 'r' => Direction::Right,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl std::ops::Not for Direction {
+    type Output = Direction;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Down => Self::Up,
+            Self::Up => Self::Down,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+impl Direction {
+    pub fn offset(&self) -> (i64, i64) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    pub fn right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub steps: u64,
+    pub color: (u8, u8, u8),
+}
+
+impl Instruction {
+    // The real instruction hidden in the colour, the first five hex digits
+    // being the distance and the last one the direction
+    pub fn decode_color(&self) -> Option<Instruction> {
+        let (r, g, b) = self.color;
+        let code = (r as u64) << 16 | (g as u64) << 8 | b as u64;
+        let direction = match code & 0xf {
+            0 => Direction::Right,
+            1 => Direction::Down,
+            2 => Direction::Left,
+            3 => Direction::Up,
+            _ => return None,
+        };
+        Some(Instruction {
+            direction,
+            steps: code >> 4,
+            color: self.color,
+        })
+    }
+}
+
+// Corners of the trench, starting and ending at the origin if it's closed
+pub fn vertices(instructions: &[Instruction]) -> Vec<(i64, i64)> {
+    let mut pos = (0, 0);
+    let mut vertices = vec![pos];
+    for instruction in instructions {
+        let (dx, dy) = instruction.direction.offset();
+        let steps = instruction.steps as i64;
+        pos = (pos.0 + dx * steps, pos.1 + dy * steps);
+        vertices.push(pos);
+    }
+    vertices
+}
+
+// Shoelace formula for the area inside the centres of the trench cubes, then
+// Pick's theorem, A = i + b / 2 - 1, for the cubes inside it. The lagoon is
+// those and the trench itself. Only right for a trench that goes back to the
+// start without crossing itself, which `plan::validate` checks.
+pub fn lagoon_area(instructions: &[Instruction]) -> i64 {
+    let twice_area = vertices(instructions)
+        .iter()
+        .circular_tuple_windows()
+        .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+        .sum::<i64>()
+        .abs();
+    let boundary: i64 = instructions
+        .iter()
+        .map(|instruction| instruction.steps as i64)
+        .sum();
+    let interior = (twice_area - boundary + 2) / 2;
+    interior + boundary
+}
+
+pub fn parse(input: &str) -> IResult<&str, Vec<Instruction>> {
+    separated_list1(
+        multispace1,
+        tuple((
+            one_of("UDLR").map(|c| c.try_into().unwrap()),
+            space1,
+            complete::u64,
+            tuple((space1, complete::char('('), complete::char('#'))),
+            hex_digit1.map(|colorcode: &str| {
+                (0..6)
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&colorcode[i..i + 2], 16).unwrap())
+                    .collect_tuple()
+                    .unwrap()
+            }),
+            complete::char(')'),
+        ))
+        .map(|(direction, _, steps, _, color, _)| Instruction {
+            direction,
+            steps,
+            color,
+        }),
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[test]
+    fn decode_color() {
+        let (_, instructions) = parse(EXAMPLE).unwrap();
+        assert_eq!(
+            instructions[0].decode_color(),
+            Some(Instruction {
+                direction: Direction::Right,
+                steps: 461937,
+                color: (0x70, 0xc7, 0x10)
+            })
+        );
+        assert_eq!(
+            instructions[1]
+                .decode_color()
+                .map(|i| (i.direction, i.steps)),
+            Some((Direction::Down, 56407))
+        );
+        assert_eq!(
+            Instruction {
+                direction: Direction::Up,
+                steps: 1,
+                color: (0, 0, 0x14)
+            }
+            .decode_color(),
+            None
+        );
+    }
+
+    #[test]
+    fn area() {
+        let (_, instructions) = parse(EXAMPLE).unwrap();
+        assert_eq!(lagoon_area(&instructions), 62);
+
+        let decoded: Vec<Instruction> = instructions
+            .iter()
+            .map(|instruction| instruction.decode_color().unwrap())
+            .collect();
+        assert_eq!(lagoon_area(&decoded), 952408144115);
+
+        // A single cube wide square, dug anticlockwise
+        let (_, square) =
+            parse("U 2 (#000000)\nL 2 (#000000)\nD 2 (#000000)\nR 2 (#000000)").unwrap();
+        assert_eq!(lagoon_area(&square), 9);
+    }
+}
@@ -0,0 +1,481 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{parse, vertices, Direction, Instruction};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Issue {
+    // The first instruction couldn't be read at all
+    Unparsable {
+        line: usize,
+        text: String,
+    },
+    // Input left over after the last instruction that could be read
+    TrailingInput {
+        line: usize,
+        text: String,
+    },
+    Empty,
+    // The last hex digit of the colour isn't one of the four directions
+    UndecodableColor {
+        instruction: usize,
+    },
+    ZeroSteps {
+        instruction: usize,
+    },
+    // Heading straight back the way the previous instruction came
+    Backtrack {
+        instruction: usize,
+    },
+    NotClosed {
+        end: (i64, i64),
+    },
+    // Two instructions that aren't next to each other dig the same cube
+    SelfIntersection {
+        first: usize,
+        second: usize,
+        at: (i64, i64),
+    },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::Unparsable { line, text } => {
+                write!(f, "error: line {line} isn't a dig instruction: {text:?}")
+            }
+            Issue::TrailingInput { line, text } => write!(
+                f,
+                "error: the dig plan stops being readable at line {line}: {text:?}"
+            ),
+            Issue::Empty => write!(f, "error: the dig plan has no instructions"),
+            Issue::UndecodableColor { instruction } => write!(
+                f,
+                "error: instruction {}'s colour doesn't end in a direction",
+                instruction + 1
+            ),
+            Issue::ZeroSteps { instruction } => {
+                write!(f, "error: instruction {} digs nothing", instruction + 1)
+            }
+            Issue::Backtrack { instruction } => write!(
+                f,
+                "error: instruction {} goes back along the trench it came from",
+                instruction + 1
+            ),
+            Issue::NotClosed { end } => write!(
+                f,
+                "error: the trench ends at {end:?} instead of going back to the start"
+            ),
+            Issue::SelfIntersection { first, second, at } => write!(
+                f,
+                "error: instructions {} and {} both dig {at:?}",
+                first + 1,
+                second + 1
+            ),
+        }
+    }
+}
+
+// Which way the trench goes round, as seen with y going down
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Winding {
+    Clockwise,
+    Anticlockwise,
+}
+
+impl Winding {
+    // The side of the trench the lagoon is on, relative to heading `direction`
+    pub fn interior(&self, direction: Direction) -> Direction {
+        match self {
+            Winding::Clockwise => direction.right(),
+            Winding::Anticlockwise => !direction.right(),
+        }
+    }
+}
+
+// Where two axis aligned segments first overlap, if they do
+fn overlap(a: ((i64, i64), (i64, i64)), b: ((i64, i64), (i64, i64))) -> Option<(i64, i64)> {
+    let x = a.0 .0.min(a.1 .0).max(b.0 .0.min(b.1 .0));
+    let y = a.0 .1.min(a.1 .1).max(b.0 .1.min(b.1 .1));
+    let fits = x <= a.0 .0.max(a.1 .0).min(b.0 .0.max(b.1 .0))
+        && y <= a.0 .1.max(a.1 .1).min(b.0 .1.max(b.1 .1));
+    fits.then_some((x, y))
+}
+
+pub fn validate(instructions: &[Instruction]) -> Result<Winding, Vec<Issue>> {
+    if instructions.is_empty() {
+        return Err(vec![Issue::Empty]);
+    }
+    let len = instructions.len();
+    let vertices = vertices(instructions);
+    let closed = vertices[len] == (0, 0);
+
+    // Every other check assumes each instruction digs at least one cube
+    let zero_steps: Vec<Issue> = instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| instruction.steps == 0)
+        .map(|(instruction, _)| Issue::ZeroSteps { instruction })
+        .collect();
+    if !zero_steps.is_empty() {
+        return Err(zero_steps);
+    }
+    let mut issues = vec![];
+
+    // Each instruction and the one before it, wrapping round if the trench
+    // is closed
+    let previous = |i: usize| (i > 0 || closed).then(|| (i + len - 1) % len);
+    issues.extend((0..len).filter_map(|i| {
+        let previous = previous(i).filter(|previous| *previous != i)?;
+        (instructions[i].direction == !instructions[previous].direction)
+            .then_some(Issue::Backtrack { instruction: i })
+    }));
+
+    if !closed {
+        issues.push(Issue::NotClosed { end: vertices[len] });
+    }
+
+    let segment = |i: usize| (vertices[i], vertices[i + 1]);
+    for second in 0..len {
+        for first in 0..second {
+            if previous(second) == Some(first) || previous(first) == Some(second) {
+                continue;
+            }
+            if let Some(at) = overlap(segment(first), segment(second)) {
+                issues.push(Issue::SelfIntersection { first, second, at });
+            }
+        }
+    }
+
+    if !issues.is_empty() {
+        return Err(issues);
+    }
+
+    // The sign of the shoelace formula
+    let twice_area: i64 = (0..len)
+        .map(|i| {
+            let (a, b) = segment(i);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum();
+    Ok(if twice_area > 0 {
+        Winding::Clockwise
+    } else {
+        Winding::Anticlockwise
+    })
+}
+
+// The line `rest` starts on and its text
+fn locate(input: &str, rest: &str) -> (usize, String) {
+    let consumed = &input[..input.len() - rest.len()];
+    let line = consumed.matches('\n').count() + 1;
+    let text = input
+        .lines()
+        .nth(line - 1)
+        .unwrap_or(rest)
+        .trim()
+        .to_string();
+    (line, text)
+}
+
+// Reads the instructions without checking the trench they dig
+fn parse_plan(input: &str) -> Result<Vec<Instruction>, Vec<Issue>> {
+    let (rest, instructions) = match parse(input) {
+        Ok(parsed) => parsed,
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            let (line, text) = locate(input, error.input);
+            return Err(vec![Issue::Unparsable { line, text }]);
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers to never be incomplete"),
+    };
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        let (line, text) = locate(input, rest);
+        return Err(vec![Issue::TrailingInput { line, text }]);
+    }
+    Ok(instructions)
+}
+
+pub fn parse_checked(input: &str) -> Result<(Vec<Instruction>, Winding), Vec<Issue>> {
+    let instructions = parse_plan(input)?;
+    let winding = validate(&instructions)?;
+    Ok((instructions, winding))
+}
+
+// The same checks on the instructions hidden in the colours
+pub fn parse_decoded(input: &str) -> Result<(Vec<Instruction>, Winding), Vec<Issue>> {
+    let instructions = parse_plan(input)?;
+    let undecodable: Vec<Issue> = instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| instruction.decode_color().is_none())
+        .map(|(instruction, _)| Issue::UndecodableColor { instruction })
+        .collect();
+    if !undecodable.is_empty() {
+        return Err(undecodable);
+    }
+    let decoded: Vec<Instruction> = instructions
+        .iter()
+        .filter_map(Instruction::decode_color)
+        .collect();
+    let winding = validate(&decoded)?;
+    Ok((decoded, winding))
+}
+
+pub fn trench(instructions: &[Instruction]) -> HashSet<(i64, i64)> {
+    let mut trench = HashSet::new();
+    let mut pos = (0, 0);
+    for instruction in instructions {
+        let (dx, dy) = instruction.direction.offset();
+        for _ in 0..instruction.steps {
+            pos = (pos.0 + dx, pos.1 + dy);
+            trench.insert(pos);
+        }
+    }
+    trench
+}
+
+// Cubes on the lagoon side of the trench that aren't part of it. Beside the
+// middle of an instruction that's the cube on its interior side, at a corner
+// it's the cube diagonally inside both instructions, which tight corners can
+// still leave outside, so these are only candidates.
+pub fn interior_seeds(
+    instructions: &[Instruction],
+    winding: Winding,
+    trench: &HashSet<(i64, i64)>,
+) -> Vec<(i64, i64)> {
+    let vertices = vertices(instructions);
+    let middles = vertices
+        .iter()
+        .zip(instructions)
+        .flat_map(|(start, instruction)| {
+            let (dx, dy) = instruction.direction.offset();
+            let (ix, iy) = winding.interior(instruction.direction).offset();
+            (1..instruction.steps as i64)
+                .map(move |k| (start.0 + dx * k + ix, start.1 + dy * k + iy))
+        });
+    let corners = (0..instructions.len()).map(|i| {
+        let (into, out) = (
+            &instructions[i],
+            &instructions[(i + 1) % instructions.len()],
+        );
+        let (ax, ay) = winding.interior(into.direction).offset();
+        let (bx, by) = winding.interior(out.direction).offset();
+        let corner = vertices[i + 1];
+        (corner.0 + ax + bx, corner.1 + ay + by)
+    });
+    middles
+        .chain(corners)
+        .filter(|cube| !trench.contains(cube))
+        .collect()
+}
+
+// Floods out from `seed` until reaching the trench, None if the flood gets
+// past the trench's bounds and so started outside of it
+fn flood(
+    trench: &HashSet<(i64, i64)>,
+    seed: (i64, i64),
+    bounds: ((i64, i64), (i64, i64)),
+) -> Option<HashSet<(i64, i64)>> {
+    let ((min_x, min_y), (max_x, max_y)) = bounds;
+    let mut lagoon = trench.clone();
+    let mut todo = vec![seed];
+    while let Some(cube) = todo.pop() {
+        if cube.0 < min_x || cube.0 > max_x || cube.1 < min_y || cube.1 > max_y {
+            return None;
+        }
+        if !lagoon.insert(cube) {
+            continue;
+        }
+        for direction in [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ] {
+            let (dx, dy) = direction.offset();
+            let next = (cube.0 + dx, cube.1 + dy);
+            if !lagoon.contains(&next) {
+                todo.push(next);
+            }
+        }
+    }
+    Some(lagoon)
+}
+
+// Digs out the trench and floods the lagoon from the first seed that's
+// inside of it. Only the trench is dug if nothing is.
+pub fn dig(instructions: &[Instruction], winding: Winding) -> HashSet<(i64, i64)> {
+    let trench = trench(instructions);
+    let xs = trench.iter().map(|cube| cube.0);
+    let ys = trench.iter().map(|cube| cube.1);
+    let bounds = (
+        (xs.clone().min().unwrap_or(0), ys.clone().min().unwrap_or(0)),
+        (xs.max().unwrap_or(0), ys.max().unwrap_or(0)),
+    );
+    interior_seeds(instructions, winding, &trench)
+        .into_iter()
+        .find_map(|seed| flood(&trench, seed, bounds))
+        .unwrap_or(trench)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lagoon_area;
+
+    const EXAMPLE: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    fn plan(steps: &str) -> Vec<Instruction> {
+        let input = steps
+            .split(", ")
+            .map(|step| format!("{step} (#000000)"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        parse(&input).unwrap().1
+    }
+
+    #[test]
+    fn winding() {
+        let (instructions, winding) = parse_checked(EXAMPLE).unwrap();
+        assert_eq!(winding, Winding::Clockwise);
+        assert_eq!(dig(&instructions, winding).len(), 62);
+
+        // The same trench dug the other way round
+        let reversed: Vec<Instruction> = instructions
+            .iter()
+            .rev()
+            .map(|instruction| Instruction {
+                direction: !instruction.direction,
+                ..*instruction
+            })
+            .collect();
+        assert_eq!(validate(&reversed), Ok(Winding::Anticlockwise));
+        assert_eq!(dig(&reversed, Winding::Anticlockwise).len(), 62);
+    }
+
+    #[test]
+    fn flood_matches_area() {
+        for steps in [
+            "R 1, D 1, L 1, U 1",
+            "D 4, R 4, U 4, L 4",
+            "R 4, D 4, L 2, U 2, L 2, U 2",
+            "U 3, R 1, D 2, R 1, U 2, R 1, D 3, L 3",
+            "R 1, D 1, R 1, D 1, L 1, D 1, L 1, D 1, L 1, U 1, L 1, U 1, R 1, U 1, R 1, U 1",
+        ] {
+            let instructions = plan(steps);
+            let winding = validate(&instructions).unwrap();
+            assert_eq!(
+                dig(&instructions, winding).len() as i64,
+                lagoon_area(&instructions),
+                "{steps}"
+            );
+        }
+    }
+
+    #[test]
+    fn unreadable_plan() {
+        assert_eq!(
+            parse_checked("R 1 (#000000)\nD 1 (#000000)\nX 1 (#000000)\nU 1 (#000000)\n"),
+            Err(vec![Issue::TrailingInput {
+                line: 3,
+                text: "X 1 (#000000)".to_string()
+            }])
+        );
+        assert_eq!(
+            parse_checked("R one (#000000)"),
+            Err(vec![Issue::Unparsable {
+                line: 1,
+                text: "R one (#000000)".to_string()
+            }])
+        );
+        assert_eq!(
+            parse_checked(""),
+            Err(vec![Issue::Unparsable {
+                line: 1,
+                text: "".to_string()
+            }])
+        );
+        assert_eq!(
+            parse_checked(&format!("{EXAMPLE}\n")).map(|(_, winding)| winding),
+            Ok(Winding::Clockwise)
+        );
+    }
+
+    #[test]
+    fn decoded_plan() {
+        let (instructions, winding) = parse_decoded(EXAMPLE).unwrap();
+        assert_eq!(winding, Winding::Clockwise);
+        assert_eq!(lagoon_area(&instructions), 952408144115);
+
+        assert_eq!(
+            parse_decoded("R 1 (#000010)\nD 1 (#000014)"),
+            Err(vec![Issue::UndecodableColor { instruction: 1 }])
+        );
+        // Reads as a closed square, but the colours only go right and down
+        assert_eq!(
+            parse_decoded("R 1 (#000010)\nD 1 (#000011)\nL 1 (#000010)\nU 1 (#000011)"),
+            Err(vec![Issue::NotClosed { end: (2, 2) }])
+        );
+    }
+
+    #[test]
+    fn backtracking() {
+        assert_eq!(
+            validate(&plan("R 3, L 1, D 1, L 2, U 1")),
+            Err(vec![
+                Issue::Backtrack { instruction: 1 },
+                Issue::SelfIntersection {
+                    first: 0,
+                    second: 2,
+                    at: (2, 0)
+                },
+            ])
+        );
+        assert_eq!(
+            validate(&plan("R 2, L 2")),
+            Err(vec![
+                Issue::Backtrack { instruction: 0 },
+                Issue::Backtrack { instruction: 1 }
+            ])
+        );
+    }
+
+    #[test]
+    fn not_closed() {
+        assert_eq!(
+            validate(&plan("R 2, D 2, L 1")),
+            Err(vec![Issue::NotClosed { end: (1, 2) }])
+        );
+        assert_eq!(validate(&[]), Err(vec![Issue::Empty]));
+        assert_eq!(
+            validate(&plan("R 1, D 0, R 1, D 1, L 2, U 1")),
+            Err(vec![Issue::ZeroSteps { instruction: 1 }])
+        );
+    }
+
+    #[test]
+    fn self_intersection() {
+        // A figure of eight crossing at (1, 0)
+        assert_eq!(
+            validate(&plan("R 2, D 1, L 1, U 2, L 1, D 1")),
+            Err(vec![Issue::SelfIntersection {
+                first: 0,
+                second: 3,
+                at: (1, 0)
+            }])
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use day_21::Garden;
+
+const STEPS: u64 = 64;
+
+fn process(input: &str, steps: u64) -> String {
+    Garden::parse(input).reachable(steps).to_string()
+}
+
+fn main() {
+    println!("Output: {}", process(include_str!("../input.txt"), STEPS));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+    const ANSWER: &str = "16";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE, 6))
+    }
+}
@@ -0,0 +1,40 @@
+use day_21::Garden;
+
+const STEPS: u64 = 26_501_365;
+
+fn process(input: &str, steps: u64) -> String {
+    match Garden::parse(input).reachable_infinite(steps) {
+        Some(plots) => plots.to_string(),
+        None => {
+            eprintln!("error: the reachable plots never settled into a quadratic");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    println!("Output: {}", process(include_str!("../input.txt"), STEPS));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+    const ANSWER: &str = "167004";
+
+    #[test]
+    fn example() {
+        assert_eq!(ANSWER, process(EXAMPLE, 500))
+    }
+}
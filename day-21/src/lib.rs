@@ -0,0 +1,292 @@
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Garden {
+    pub width: usize,
+    pub height: usize,
+    pub rocks: Vec<bool>,
+    pub start: (usize, usize),
+}
+
+const OFFSETS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+impl Garden {
+    pub fn parse(input: &str) -> Self {
+        let mut start = None;
+        let mut rocks = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            width = line.len();
+            height += 1;
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => rocks.push(true),
+                    '.' => rocks.push(false),
+                    'S' => {
+                        start = Some((x, y));
+                        rocks.push(false);
+                    }
+                    _ => panic!("unexpected tile {c:?}"),
+                }
+            }
+        }
+
+        Garden {
+            width,
+            height,
+            rocks,
+            start: start.expect("garden to have a start"),
+        }
+    }
+
+    // Whether there's a rock at `x`, `y` when the garden repeats forever
+    pub fn is_rock(&self, x: i64, y: i64) -> bool {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        self.rocks[y * self.width + x]
+    }
+
+    // Fewest steps from the start to each plot, None for rocks and plots
+    // walled off by them
+    pub fn distances(&self) -> Vec<Option<u64>> {
+        let mut distances = vec![None; self.rocks.len()];
+        let mut queue = VecDeque::from([(self.start, 0)]);
+        distances[self.start.1 * self.width + self.start.0] = Some(0);
+        while let Some(((x, y), distance)) = queue.pop_front() {
+            for (dx, dy) in OFFSETS {
+                let (x, y) = (x as i64 + dx, y as i64 + dy);
+                if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+                    continue;
+                }
+                let (x, y) = (x as usize, y as usize);
+                let index = y * self.width + x;
+                if !self.rocks[index] && distances[index].is_none() {
+                    distances[index] = Some(distance + 1);
+                    queue.push_back(((x, y), distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    // The elf can step back and forth, so a plot can be ended on after exactly
+    // `steps` when it's reachable in fewer steps of the same parity
+    pub fn reachable(&self, steps: u64) -> usize {
+        self.distances()
+            .into_iter()
+            .flatten()
+            .filter(|distance| *distance <= steps && distance % 2 == steps % 2)
+            .count()
+    }
+
+    // Plots reachable after each of `0..=steps` in the infinite garden, by
+    // walking it one step at a time
+    pub fn reachable_infinite_counts(&self, steps: u64) -> Vec<u64> {
+        let mut walk = Walk::new(self);
+        walk.extend(steps);
+        walk.counts
+    }
+
+    // Once the walk has spread over enough copies of the garden, every extra
+    // period it spreads, the width and height both being whole numbers of
+    // copies, adds a ring of copies that grows by the same amount each time.
+    // So the counts at `steps % period` plus whole periods are a quadratic,
+    // which is walked until the second differences settle and extrapolated
+    // from there. None if they don't settle within `MAX_PERIODS` or the
+    // count doesn't fit.
+    pub fn reachable_infinite(&self, steps: u64) -> Option<u64> {
+        let period = lcm(self.width, self.height) as u64;
+        let remainder = steps % period;
+
+        let mut walk = Walk::new(self);
+        for periods in 0..=MAX_PERIODS {
+            let walked = remainder + periods * period;
+            walk.extend(walked);
+            if steps <= walked {
+                return Some(walk.counts[steps as usize]);
+            }
+            let samples: Vec<u64> = (remainder..=walked)
+                .step_by(period as usize)
+                .map(|step| walk.counts[step as usize])
+                .collect();
+            if let Some(quadratic) = Quadratic::settled(&samples) {
+                return quadratic.at((steps - remainder) / period);
+            }
+        }
+        None
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    a / x * b
+}
+
+// A walk through the infinite garden that can be taken further, keeping the
+// plots reached so far
+struct Walk<'a> {
+    garden: &'a Garden,
+    seen: HashSet<(i64, i64)>,
+    frontier: Vec<(i64, i64)>,
+    // Plots first reached on an even and an odd step
+    parity: [u64; 2],
+    // Plots reachable after each step walked so far
+    counts: Vec<u64>,
+}
+
+impl<'a> Walk<'a> {
+    fn new(garden: &'a Garden) -> Self {
+        let start = (garden.start.0 as i64, garden.start.1 as i64);
+        Walk {
+            garden,
+            seen: HashSet::from([start]),
+            frontier: vec![start],
+            parity: [1, 0],
+            counts: vec![1],
+        }
+    }
+
+    fn extend(&mut self, steps: u64) {
+        for step in self.counts.len() as u64..=steps {
+            let mut next = vec![];
+            for (x, y) in self.frontier.drain(..) {
+                for (dx, dy) in OFFSETS {
+                    let plot = (x + dx, y + dy);
+                    if !self.garden.is_rock(plot.0, plot.1) && self.seen.insert(plot) {
+                        next.push(plot);
+                    }
+                }
+            }
+            self.parity[step as usize % 2] += next.len() as u64;
+            self.counts.push(self.parity[step as usize % 2]);
+            self.frontier = next;
+        }
+    }
+}
+
+// How many second differences in a row have to match before trusting them
+const SETTLE: u64 = 4;
+// How many periods to walk before giving up on them settling
+const MAX_PERIODS: u64 = 16;
+
+// The last sample and its first and second differences, indexed by how many
+// garden widths past the remainder it was taken
+struct Quadratic {
+    n: u64,
+    value: i128,
+    first: i128,
+    second: i128,
+}
+
+impl Quadratic {
+    fn settled(samples: &[u64]) -> Option<Self> {
+        let values: Vec<i128> = samples.iter().map(|value| *value as i128).collect();
+        let first: Vec<i128> = values.windows(2).map(|w| w[1] - w[0]).collect();
+        let second: Vec<i128> = first.windows(2).map(|w| w[1] - w[0]).collect();
+        let tail = second.get(second.len().checked_sub(SETTLE as usize)?..)?;
+        tail.iter()
+            .all(|second| *second == tail[0])
+            .then(|| Quadratic {
+                n: samples.len() as u64 - 1,
+                value: values[values.len() - 1],
+                first: first[first.len() - 1],
+                second: tail[0],
+            })
+    }
+
+    fn at(&self, n: u64) -> Option<u64> {
+        let k = n as i128 - self.n as i128;
+        let value = self
+            .second
+            .checked_mul(k)?
+            .checked_mul(k + 1)?
+            .checked_div(2)?
+            .checked_add(self.first.checked_mul(k)?)?
+            .checked_add(self.value)?;
+        u64::try_from(value).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[test]
+    fn finite_garden() {
+        let garden = Garden::parse(EXAMPLE);
+        assert_eq!(garden.reachable(1), 2);
+        assert_eq!(garden.reachable(2), 4);
+        assert_eq!(garden.reachable(6), 16);
+        // Every plot the elf can get to, on the matching parity
+        assert_eq!(garden.reachable(100), 42);
+    }
+
+    #[test]
+    fn walking_infinite_garden() {
+        let garden = Garden::parse(EXAMPLE);
+        let counts = garden.reachable_infinite_counts(100);
+        assert_eq!(counts[6], 16);
+        assert_eq!(counts[10], 50);
+        assert_eq!(counts[50], 1594);
+        assert_eq!(counts[100], 6536);
+    }
+
+    fn counts_at(garden: &Garden, steps: u64) -> u64 {
+        garden.reachable_infinite_counts(steps)[steps as usize]
+    }
+
+    #[test]
+    fn extrapolated_infinite_garden() {
+        let garden = Garden::parse(EXAMPLE);
+        assert_eq!(garden.reachable_infinite(6), Some(16));
+        assert_eq!(garden.reachable_infinite(10), Some(50));
+        assert_eq!(garden.reachable_infinite(50), Some(1594));
+        assert_eq!(garden.reachable_infinite(100), Some(6536));
+        assert_eq!(garden.reachable_infinite(80), Some(counts_at(&garden, 80)));
+        assert_eq!(garden.reachable_infinite(500), Some(167004));
+        assert_eq!(garden.reachable_infinite(1000), Some(668697));
+        assert_eq!(garden.reachable_infinite(5000), Some(16733044));
+        assert_eq!(garden.reachable_infinite(u64::MAX), None);
+    }
+
+    #[test]
+    fn extrapolated_matches_walking() {
+        let garden = Garden::parse(EXAMPLE);
+        // Around the first sampled window, where steps can fall between samples
+        let counts = garden.reachable_infinite_counts(98);
+        for steps in 66..=98 {
+            assert_eq!(
+                garden.reachable_infinite(steps),
+                Some(counts[steps as usize])
+            );
+        }
+    }
+
+    #[test]
+    fn rectangular_garden() {
+        let garden = Garden::parse(".....\n.#S..\n...#.");
+        let counts = garden.reachable_infinite_counts(200);
+        for steps in 150..=200 {
+            assert_eq!(
+                garden.reachable_infinite(steps),
+                Some(counts[steps as usize])
+            );
+        }
+    }
+}